@@ -1,4 +1,6 @@
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
 use generational_vector::{vector, GenerationalVector};
 use std::num::NonZeroUsize;
 
@@ -52,6 +54,99 @@ fn criterion_benchmark(c: &mut Criterion) {
         });
     }
     group.finish();
+
+    let mut group = c.benchmark_group("gv: lookup");
+    for size in [16, 1024, 65536].iter() {
+        let mut vec = GenerationalVector::default();
+        let idxs: Vec<_> = (0..*size).map(|value| vec.push(value)).collect();
+
+        group.throughput(Throughput::Elements(*size as _));
+        group.bench_with_input(BenchmarkId::new("get", size), size, |b, _| {
+            b.iter(|| {
+                let mut sum = 0usize;
+                for idx in &idxs {
+                    sum += vec.get(black_box(idx)).unwrap();
+                }
+                sum
+            });
+        });
+        group.bench_with_input(BenchmarkId::new("get_unchecked", size), size, |b, _| {
+            b.iter(|| {
+                let mut sum = 0usize;
+                for idx in &idxs {
+                    // SAFETY: All indices were just issued and none were removed.
+                    sum += unsafe { vec.get_unchecked(black_box(idx)) };
+                }
+                sum
+            });
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("gv: batch remove");
+    for size in [16, 1024, 65536].iter() {
+        let setup = || {
+            let mut vec = GenerationalVector::default();
+            let idxs: Vec<_> = (0..*size).map(|value| vec.push(value)).collect();
+            (vec, idxs)
+        };
+
+        group.throughput(Throughput::Elements(*size as _));
+        group.bench_with_input(BenchmarkId::new("remove", size), size, |b, _| {
+            b.iter_batched(
+                setup,
+                |(mut vec, idxs)| {
+                    for idx in &idxs {
+                        vec.remove(black_box(idx));
+                    }
+                    vec
+                },
+                BatchSize::LargeInput,
+            );
+        });
+        group.bench_with_input(BenchmarkId::new("remove_sorted", size), size, |b, _| {
+            b.iter_batched(
+                setup,
+                |(mut vec, idxs)| {
+                    vec.remove_sorted(black_box(&idxs));
+                    vec
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("gv: free list preallocation");
+    for size in [16, 1024, 65536].iter() {
+        let fill = |mut vec: GenerationalVector<usize>| {
+            let idxs: Vec<_> = (0..*size).map(|value| vec.push(value)).collect();
+            (vec, idxs)
+        };
+        let remove_all = |(mut vec, idxs): (GenerationalVector<usize>, Vec<_>)| {
+            for idx in &idxs {
+                vec.remove(black_box(idx));
+            }
+            vec
+        };
+
+        group.throughput(Throughput::Elements(*size as _));
+        group.bench_with_input(BenchmarkId::new("default", size), size, |b, &size| {
+            b.iter_batched(
+                || fill(GenerationalVector::with_capacity(size)),
+                remove_all,
+                BatchSize::LargeInput,
+            );
+        });
+        group.bench_with_input(BenchmarkId::new("preallocated", size), size, |b, &size| {
+            b.iter_batched(
+                || fill(GenerationalVector::with_free_list_capacity(size, size)),
+                remove_all,
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
 }
 
 criterion_group!(benches, criterion_benchmark);
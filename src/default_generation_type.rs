@@ -1,9 +1,10 @@
-use num_traits::One;
-use std::num::NonZeroUsize;
-use std::ops::{Add, AddAssign, Deref, Mul};
+use core::num::{NonZeroU32, NonZeroUsize};
+use core::ops::{Add, AddAssign, Deref, Mul};
+use num_traits::{Bounded, CheckedAdd, One, ToPrimitive};
 
 /// The default generation type.
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DefaultGenerationType(NonZeroUsize);
 
 impl Default for DefaultGenerationType {
@@ -38,15 +39,72 @@ impl One for DefaultGenerationType {
     }
 }
 
+impl DefaultGenerationType {
+    /// Adds two generations, saturating at [`Bounded::max_value`] instead of
+    /// panicking on overflow.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::DefaultGenerationType;
+    /// use num_traits::{Bounded, One};
+    ///
+    /// let max = DefaultGenerationType::max_value();
+    /// assert_eq!(max.saturating_add(DefaultGenerationType::one()), max);
+    /// ```
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0.get()))
+    }
+}
+
 impl Add for DefaultGenerationType {
     type Output = DefaultGenerationType;
 
+    /// Adds two generations.
+    ///
+    /// ## Panics
+    /// Panics if the sum exceeds [`Bounded::max_value`]; use
+    /// [`saturating_add`](Self::saturating_add) or [`CheckedAdd`] to handle
+    /// overflows explicitly.
     fn add(self, rhs: Self) -> Self::Output {
-        let value = self.0.get() + rhs.0.get();
-        if value == 0 {
-            panic!("overflow of generation value")
-        }
-        Self(unsafe { NonZeroUsize::new_unchecked(value) })
+        self.checked_add(&rhs)
+            .expect("generation counter overflowed")
+    }
+}
+
+impl CheckedAdd for DefaultGenerationType {
+    #[inline]
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        self.0.checked_add(rhs.0.get()).map(Self)
+    }
+}
+
+impl Bounded for DefaultGenerationType {
+    #[inline]
+    fn min_value() -> Self {
+        Self::one()
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self(NonZeroUsize::MAX)
+    }
+}
+
+impl ToPrimitive for DefaultGenerationType {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.0.get().to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.0.get().to_u64()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        self.0.get().to_u128()
     }
 }
 
@@ -55,3 +113,125 @@ impl AddAssign for DefaultGenerationType {
         self.0 = (*self + rhs).0;
     }
 }
+
+/// A generation type that wraps around to one instead of overflowing.
+///
+/// Slots using this type can be reused indefinitely without ever panicking or
+/// being retired, since [`CheckedAdd`] never fails either. The tradeoff is the
+/// ABA problem: after `u32::MAX` reuses of a slot, a stale index becomes valid
+/// again and silently refers to an unrelated element.
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WrappingGenerationType(NonZeroU32);
+
+impl Default for WrappingGenerationType {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::one()
+    }
+}
+
+impl Deref for WrappingGenerationType {
+    type Target = NonZeroU32;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Mul for WrappingGenerationType {
+    type Output = WrappingGenerationType;
+
+    /// Multiplies two generations, wrapping around past [`Bounded::max_value`]
+    /// the same way [`Add`] does.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::WrappingGenerationType;
+    /// use num_traits::{Bounded, One};
+    ///
+    /// let one = WrappingGenerationType::one();
+    /// let two = one + one;
+    /// let max = WrappingGenerationType::max_value();
+    /// assert_eq!(two * one, two);
+    /// assert_eq!(max * two, max);
+    /// assert_eq!((max + two) * two, two + two);
+    /// ```
+    fn mul(self, rhs: Self) -> Self::Output {
+        // The product of two u32 values fits into a u64; wrap it like a sum.
+        let value = (self.0.get() as u64 * rhs.0.get() as u64 - 1) % u32::MAX as u64 + 1;
+        Self(NonZeroU32::new(value as u32).expect("wrapped generation is non-zero"))
+    }
+}
+
+impl One for WrappingGenerationType {
+    #[inline]
+    fn one() -> Self {
+        Self(unsafe { NonZeroU32::new_unchecked(1) })
+    }
+}
+
+impl Add for WrappingGenerationType {
+    type Output = WrappingGenerationType;
+
+    /// Adds two generations, wrapping around past [`Bounded::max_value`]
+    /// back to one, skipping zero.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::WrappingGenerationType;
+    /// use num_traits::{Bounded, One};
+    ///
+    /// let one = WrappingGenerationType::one();
+    /// assert_eq!(WrappingGenerationType::max_value() + one, one);
+    /// ```
+    fn add(self, rhs: Self) -> Self::Output {
+        // Shift the range 1..=MAX down to 0..MAX to wrap, then back up.
+        let value = (self.0.get() as u64 - 1 + rhs.0.get() as u64) % u32::MAX as u64 + 1;
+        Self(NonZeroU32::new(value as u32).expect("wrapped generation is non-zero"))
+    }
+}
+
+impl CheckedAdd for WrappingGenerationType {
+    /// Adds two generations like [`Add`]; never returns `None`.
+    #[inline]
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(*self + *rhs)
+    }
+}
+
+impl Bounded for WrappingGenerationType {
+    #[inline]
+    fn min_value() -> Self {
+        Self::one()
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self(NonZeroU32::MAX)
+    }
+}
+
+impl ToPrimitive for WrappingGenerationType {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.0.get().to_i64()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.0.get().to_u64()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        self.0.get().to_u128()
+    }
+}
+
+impl AddAssign for WrappingGenerationType {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 = (*self + rhs).0;
+    }
+}
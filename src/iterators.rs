@@ -1,14 +1,27 @@
-use crate::vector::GenerationalEntry;
-use crate::GenerationType;
+//! Iterator implementations.
 
-///! Iterator implementations.
+use crate::vector::{GenerationalEntry, GenerationalIndex};
+use crate::GenerationType;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
 
 /// Iterator for owned values.
 pub struct EntryIntoIterator<TEntry, TGeneration>
 where
     TGeneration: GenerationType,
 {
-    pub(crate) vec: Vec<GenerationalEntry<TEntry, TGeneration>>,
+    pub(crate) current: usize,
+    /// The number of elements not yet yielded.
+    pub(crate) remaining: usize,
+    pub(crate) vec: alloc::vec::IntoIter<GenerationalEntry<TEntry, TGeneration>>,
+}
+
+/// Iterator for owned values and their indices.
+pub struct IndexedEntryIntoIterator<TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    inner: EntryIntoIterator<TEntry, TGeneration>,
 }
 
 /// Iterator for owned values.
@@ -17,17 +30,58 @@ where
     TGeneration: GenerationType,
 {
     pub(crate) current: usize,
+    /// One past the last slot not yet visited from the back.
+    pub(crate) end: usize,
+    /// The number of elements not yet yielded.
+    pub(crate) remaining: usize,
     pub(crate) vec: &'a Vec<GenerationalEntry<TEntry, TGeneration>>,
 }
 
+/// Iterator for references to values and their indices.
+pub struct IndexedEntryIterator<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    inner: EntryIterator<'a, TEntry, TGeneration>,
+}
+
+/// Iterator for the indices of values.
+pub struct Keys<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    pub(crate) inner: EntryIterator<'a, TEntry, TGeneration>,
+}
+
 pub struct EntryMutIterator<'a, TEntry, TGeneration>
 where
     TGeneration: GenerationType,
 {
     pub(crate) current: usize,
+    /// One past the last slot not yet visited from the back.
+    pub(crate) end: usize,
+    /// The number of elements not yet yielded.
+    pub(crate) remaining: usize,
     pub(crate) vec: &'a mut Vec<GenerationalEntry<TEntry, TGeneration>>,
 }
 
+/// Iterator for mutable references to values and their indices.
+pub struct IndexedEntryMutIterator<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    inner: EntryMutIterator<'a, TEntry, TGeneration>,
+}
+
+/// Iterator for references to values matching a predicate, and their indices.
+pub struct FilterIndices<'a, TEntry, TGeneration, P>
+where
+    TGeneration: GenerationType,
+{
+    inner: EntryIterator<'a, TEntry, TGeneration>,
+    predicate: P,
+}
+
 impl<TEntry, TGeneration> Iterator for EntryIntoIterator<TEntry, TGeneration>
 where
     TGeneration: GenerationType,
@@ -35,10 +89,53 @@ where
     type Item = TEntry;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while !self.vec.is_empty() {
-            match self.vec.pop() {
-                None => continue,
-                Some(entry) => return entry.entry,
+        self.next_indexed().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<TEntry, TGeneration> ExactSizeIterator for EntryIntoIterator<TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<TEntry, TGeneration> FusedIterator for EntryIntoIterator<TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<TEntry, TGeneration> EntryIntoIterator<TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    /// Converts this iterator into one that also yields the index of each element.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let b = gv.push("b");
+    ///
+    /// let pairs: Vec<_> = gv.into_iter().with_handles().collect();
+    /// assert_eq!(pairs, vec![(a, "a"), (b, "b")]);
+    /// ```
+    pub fn with_handles(self) -> IndexedEntryIntoIterator<TEntry, TGeneration> {
+        IndexedEntryIntoIterator { inner: self }
+    }
+
+    fn next_indexed(&mut self) -> Option<(GenerationalIndex<TGeneration>, TEntry)> {
+        for entry in self.vec.by_ref() {
+            let index = self.current;
+            self.current += 1;
+
+            if let Some(value) = entry.entry {
+                self.remaining -= 1;
+                return Some((GenerationalIndex::new(index, entry.generation), value));
             }
         }
 
@@ -46,6 +143,31 @@ where
     }
 }
 
+impl<TEntry, TGeneration> Iterator for IndexedEntryIntoIterator<TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    type Item = (GenerationalIndex<TGeneration>, TEntry);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_indexed()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<TEntry, TGeneration> ExactSizeIterator for IndexedEntryIntoIterator<TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<TEntry, TGeneration> FusedIterator for IndexedEntryIntoIterator<TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
 impl<'a, TEntry, TGeneration> Iterator for EntryIterator<'a, TEntry, TGeneration>
 where
     TGeneration: GenerationType,
@@ -53,17 +175,225 @@ where
     type Item = &'a TEntry;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while self.current < self.vec.len() {
+        self.next_indexed().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, TEntry, TGeneration> ExactSizeIterator for EntryIterator<'a, TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<'a, TEntry, TGeneration> FusedIterator for EntryIterator<'a, TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<'a, TEntry, TGeneration> DoubleEndedIterator for EntryIterator<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_back_indexed().map(|(_, value)| value)
+    }
+}
+
+impl<'a, TEntry, TGeneration> EntryIterator<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    /// Converts this iterator into one that also yields the index of each element.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    ///
+    /// let pairs: Vec<_> = gv.iter().indexed().collect();
+    /// assert_eq!(pairs, vec![(a, &"a")]);
+    /// ```
+    pub fn indexed(self) -> IndexedEntryIterator<'a, TEntry, TGeneration> {
+        IndexedEntryIterator { inner: self }
+    }
+
+    /// Converts this iterator into one that only yields the values matching
+    /// a predicate, along with their indices.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let _a = gv.push(1);
+    /// let b = gv.push(2);
+    ///
+    /// let even: Vec<_> = gv.iter().filter_indices(|&value| value % 2 == 0).collect();
+    /// assert_eq!(even, vec![(b, &2)]);
+    /// ```
+    pub fn filter_indices<P>(self, predicate: P) -> FilterIndices<'a, TEntry, TGeneration, P>
+    where
+        P: FnMut(&TEntry) -> bool,
+    {
+        FilterIndices {
+            inner: self,
+            predicate,
+        }
+    }
+
+    /// Clears `target` and pushes the references to all remaining values into
+    /// it, reusing its capacity, e.g. to gather references into a scratch buffer.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let gv: GenerationalVector<_> = vec![1, 2].into();
+    /// let mut scratch = vec![&0];
+    ///
+    /// gv.iter().collect_into(&mut scratch);
+    /// assert_eq!(scratch, vec![&1, &2]);
+    /// ```
+    pub fn collect_into(self, target: &mut Vec<&'a TEntry>) {
+        target.clear();
+        target.extend(self);
+    }
+
+    fn next_indexed(&mut self) -> Option<(GenerationalIndex<TGeneration>, &'a TEntry)> {
+        while self.current < self.end {
             let entry = &self.vec[self.current];
+            let index = self.current;
             self.current += 1;
-            let entry = entry.entry.as_ref();
-            if entry.is_some() {
-                return Some(entry.unwrap());
+            if let Some(value) = entry.entry.as_ref() {
+                self.remaining -= 1;
+                return Some((GenerationalIndex::new(index, entry.generation), value));
+            }
+        }
+
+        None
+    }
+
+    fn next_back_indexed(&mut self) -> Option<(GenerationalIndex<TGeneration>, &'a TEntry)> {
+        while self.current < self.end {
+            self.end -= 1;
+            let entry = &self.vec[self.end];
+            if let Some(value) = entry.entry.as_ref() {
+                self.remaining -= 1;
+                return Some((GenerationalIndex::new(self.end, entry.generation), value));
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, TEntry, TGeneration> Iterator for IndexedEntryIterator<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    type Item = (GenerationalIndex<TGeneration>, &'a TEntry);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_indexed()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, TEntry, TGeneration> ExactSizeIterator for IndexedEntryIterator<'a, TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<'a, TEntry, TGeneration> FusedIterator for IndexedEntryIterator<'a, TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<'a, TEntry, TGeneration> DoubleEndedIterator for IndexedEntryIterator<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back_indexed()
+    }
+}
+
+impl<'a, TEntry, TGeneration> Iterator for Keys<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    type Item = GenerationalIndex<TGeneration>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_indexed().map(|(index, _)| index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, TEntry, TGeneration> ExactSizeIterator for Keys<'a, TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<'a, TEntry, TGeneration> FusedIterator for Keys<'a, TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<'a, TEntry, TGeneration> DoubleEndedIterator for Keys<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back_indexed().map(|(index, _)| index)
+    }
+}
+
+impl<'a, TEntry, TGeneration, P> Iterator for FilterIndices<'a, TEntry, TGeneration, P>
+where
+    TGeneration: GenerationType,
+    P: FnMut(&TEntry) -> bool,
+{
+    type Item = (GenerationalIndex<TGeneration>, &'a TEntry);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Only matching values need their index reconstructed.
+        while self.inner.current < self.inner.end {
+            let entry = &self.inner.vec[self.inner.current];
+            let index = self.inner.current;
+            self.inner.current += 1;
+            if let Some(value) = entry.entry.as_ref() {
+                self.inner.remaining -= 1;
+                if (self.predicate)(value) {
+                    return Some((GenerationalIndex::new(index, entry.generation), value));
+                }
             }
         }
 
         None
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.inner.remaining))
+    }
+}
+
+impl<'a, TEntry, TGeneration, P> FusedIterator for FilterIndices<'a, TEntry, TGeneration, P>
+where
+    TGeneration: GenerationType,
+    P: FnMut(&TEntry) -> bool,
+{
 }
 
 impl<'a, TEntry, TGeneration> Iterator for EntryMutIterator<'a, TEntry, TGeneration>
@@ -73,18 +403,144 @@ where
     type Item = &'a mut TEntry;
 
     fn next(&mut self) -> Option<Self::Item> {
+        self.next_indexed().map(|(_, value)| value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, TEntry, TGeneration> ExactSizeIterator for EntryMutIterator<'a, TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<'a, TEntry, TGeneration> FusedIterator for EntryMutIterator<'a, TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<'a, TEntry, TGeneration> DoubleEndedIterator for EntryMutIterator<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.next_back_indexed().map(|(_, value)| value)
+    }
+}
+
+impl<'a, TEntry, TGeneration> EntryMutIterator<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    /// Converts this iterator into one that also yields the index of each element.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push(1);
+    ///
+    /// for (index, value) in gv.iter_mut().indexed() {
+    ///     assert_eq!(index, a);
+    ///     *value += 1;
+    /// }
+    /// assert_eq!(gv.get(&a), Some(&2));
+    /// ```
+    pub fn indexed(self) -> IndexedEntryMutIterator<'a, TEntry, TGeneration> {
+        IndexedEntryMutIterator { inner: self }
+    }
+
+    /// Calls a closure with the index of and a mutable reference to each
+    /// remaining value, e.g. for updates that depend on the index.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push((None, "a"));
+    ///
+    /// gv.iter_mut()
+    ///     .for_each_indexed(|index, (own, _)| *own = Some(index));
+    /// assert_eq!(gv.get(&a), Some(&(Some(a), "a")));
+    /// ```
+    pub fn for_each_indexed(
+        mut self,
+        mut f: impl FnMut(GenerationalIndex<TGeneration>, &mut TEntry),
+    ) {
+        while let Some((index, value)) = self.next_indexed() {
+            f(index, value);
+        }
+    }
+
+    fn next_indexed(&mut self) -> Option<(GenerationalIndex<TGeneration>, &'a mut TEntry)> {
         let ptr = self.vec.as_mut_ptr();
 
-        while self.current < self.vec.len() {
+        while self.current < self.end {
             let element = unsafe { &mut *ptr.add(self.current) };
-            let entry = element.entry.as_mut();
+            let index = self.current;
             self.current += 1;
 
-            if entry.is_some() {
-                return entry;
+            if let Some(value) = element.entry.as_mut() {
+                self.remaining -= 1;
+                return Some((GenerationalIndex::new(index, element.generation), value));
             }
         }
 
         None
     }
+
+    fn next_back_indexed(&mut self) -> Option<(GenerationalIndex<TGeneration>, &'a mut TEntry)> {
+        let ptr = self.vec.as_mut_ptr();
+
+        while self.current < self.end {
+            self.end -= 1;
+            let element = unsafe { &mut *ptr.add(self.end) };
+
+            if let Some(value) = element.entry.as_mut() {
+                self.remaining -= 1;
+                return Some((GenerationalIndex::new(self.end, element.generation), value));
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, TEntry, TGeneration> Iterator for IndexedEntryMutIterator<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    type Item = (GenerationalIndex<TGeneration>, &'a mut TEntry);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next_indexed()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, TEntry, TGeneration> ExactSizeIterator for IndexedEntryMutIterator<'a, TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<'a, TEntry, TGeneration> FusedIterator for IndexedEntryMutIterator<'a, TEntry, TGeneration> where
+    TGeneration: GenerationType
+{
+}
+
+impl<'a, TEntry, TGeneration> DoubleEndedIterator
+    for IndexedEntryMutIterator<'a, TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back_indexed()
+    }
 }
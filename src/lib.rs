@@ -1,17 +1,51 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 mod default_generation_type;
 pub mod iterators;
 pub mod vector;
 
-pub use default_generation_type::DefaultGenerationType;
-use num_traits::One;
-use std::ops::Add;
-pub use vector::DeletionResult;
+use core::ops::Add;
+pub use default_generation_type::{DefaultGenerationType, WrappingGenerationType};
+use num_traits::{Bounded, CheckedAdd, One, ToPrimitive};
+pub use vector::{
+    DeletionResult, GenerationOverflow, GrowthPolicy, LookupError, OverflowPolicy, RetainReport,
+    VectorStats,
+};
 
 /// Type alias to simplify construction of generational vectors.
 pub type GenerationalVector<T> = vector::GenerationalVector<T, DefaultGenerationType>;
 
 /// Alias for required traits on the type used for the generation value.
-pub trait GenerationType: One + Copy + Add<Output = Self> + PartialEq {}
+pub trait GenerationType:
+    One + Copy + Add<Output = Self> + CheckedAdd + Bounded + PartialEq
+{
+    /// Returns the largest generation value representable by this type.
+    #[inline(always)]
+    fn max_generation() -> Self {
+        Self::max_value()
+    }
+}
 
 /// Automatic implementation of `GenerationType` for all matching types.
-impl<T> GenerationType for T where T: One + Copy + Add<Output = T> + PartialEq {}
+impl<T> GenerationType for T where T: One + Copy + Add<Output = T> + CheckedAdd + Bounded + PartialEq
+{}
+
+/// Conversion of generations to `u128`, available for all generation types
+/// implementing `num_traits::ToPrimitive`.
+pub trait GenerationAsU128: GenerationType + ToPrimitive {
+    /// Converts the generation to a `u128`, e.g. to log generations
+    /// uniformly regardless of the generation type.
+    ///
+    /// ## Panics
+    /// Panics if the generation is negative, which cannot happen for
+    /// generations produced by a vector.
+    #[inline(always)]
+    fn as_u128(self) -> u128 {
+        self.to_u128().expect("generation must not be negative")
+    }
+}
+
+/// Automatic implementation of `GenerationAsU128` for all matching types.
+impl<T> GenerationAsU128 for T where T: GenerationType + ToPrimitive {}
@@ -1,23 +1,40 @@
 #[cfg(all(feature = "tinyvec", feature = "smallvec"))]
 compile_error!("Feature \"tinyvec\" and \"smallvec\" cannot be enabled at the same time");
 
-use crate::iterators::{EntryIntoIterator, EntryIterator, EntryMutIterator};
-use crate::{DefaultGenerationType, GenerationType};
-use std::borrow::Borrow;
-use std::fmt::Debug;
+use crate::iterators::{
+    EntryIntoIterator, EntryIterator, EntryMutIterator, IndexedEntryIterator,
+    IndexedEntryMutIterator, Keys,
+};
+use crate::{DefaultGenerationType, GenerationAsU128, GenerationType};
+use alloc::boxed::Box;
+use alloc::collections::TryReserveError;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter, Write};
+use core::hash::Hash;
+use core::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+use num_traits::ToPrimitive;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
 
 /// An index entry in the `GenerationalVector`.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GenerationalIndex<TGeneration> {
     index: usize,
     generation: TGeneration,
 }
 
 /// An index entry
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub(crate) struct GenerationalEntry<TEntry, TGeneration> {
     /// The generation of the entry. A value of zero always encodes an empty value.
-    generation: TGeneration,
+    pub(crate) generation: TGeneration,
     /// The data of this entry.
     pub(crate) entry: Option<TEntry>,
 }
@@ -31,14 +48,30 @@ where
         self.generation == index.generation
     }
 
+    /// Advances the generation of this entry, applying the specified policy
+    /// if the generation cannot be incremented any further.
+    ///
+    /// ## Returns
+    /// `false` if the slot must be retired; `true` otherwise.
     #[inline(always)]
-    fn reset_and_evolve(&mut self) {
-        self.entry = None;
-        self.generation = self.generation.add(TGeneration::one())
+    fn evolve(&mut self, policy: OverflowPolicy) -> bool {
+        match self.generation.checked_add(&TGeneration::one()) {
+            Some(generation) => self.generation = generation,
+            None => match policy {
+                OverflowPolicy::Panic => panic!("generation counter overflowed"),
+                OverflowPolicy::Saturate => {}
+                OverflowPolicy::Retire => return false,
+                OverflowPolicy::Wrap => self.generation = TGeneration::one(),
+            },
+        }
+
+        true
     }
 }
 
-const FREE_LIST_CAPACITY: usize = 16;
+/// The number of free slots the free list holds without allocating when the
+/// `smallvec` or `tinyvec` feature is enabled; also its initial capacity otherwise.
+pub const FREE_LIST_CAPACITY: usize = 16;
 
 #[cfg(not(any(feature = "smallvec", feature = "tinyvec")))]
 type FreeList = Vec<usize>;
@@ -50,13 +83,47 @@ type FreeList = smallvec::SmallVec<[usize; FREE_LIST_CAPACITY]>;
 type FreeList = tinyvec::TinyVec<[usize; FREE_LIST_CAPACITY]>;
 
 /// A vector that utilizes generational indexing to access the elements.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "SerializedGenerationalVector<TEntry, TGeneration>")
+)]
 pub struct GenerationalVector<TEntry, TGeneration = DefaultGenerationType>
 where
     TGeneration: GenerationType,
 {
     data: Vec<GenerationalEntry<TEntry, TGeneration>>,
     free_list: FreeList,
+    /// Slots that reached their maximum generation and are no longer reused.
+    retired: Vec<usize>,
+    /// The maximum number of live elements accepted by `try_push`.
+    max_capacity: Option<usize>,
+    /// The behavior when a slot's generation cannot be advanced any further.
+    overflow_policy: OverflowPolicy,
+    /// The strategy for growing the backing storage when pushing.
+    growth_policy: GrowthPolicy,
+    /// The generation of slots appended to the end of the vector. It is never
+    /// lower than the generation of any slot trimmed from the end, so that
+    /// stale indices to trimmed slots cannot become valid again.
+    tail_generation: TGeneration,
+}
+
+/// The unvalidated serialized form of a `GenerationalVector`.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SerializedGenerationalVector<TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    data: Vec<GenerationalEntry<TEntry, TGeneration>>,
+    free_list: FreeList,
+    retired: Vec<usize>,
+    max_capacity: Option<usize>,
+    overflow_policy: OverflowPolicy,
+    growth_policy: GrowthPolicy,
+    #[serde(default = "num_traits::One::one")]
+    tail_generation: TGeneration,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
@@ -69,6 +136,109 @@ pub enum DeletionResult {
     InvalidGeneration,
 }
 
+/// The reason an index could not be resolved to a value.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LookupError {
+    /// The index refers to a slot beyond the end of the vector.
+    OutOfBounds,
+    /// The slot exists but is empty.
+    NotFound,
+    /// The slot has moved on to a different generation.
+    InvalidGeneration,
+}
+
+impl Display for LookupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            LookupError::OutOfBounds => write!(f, "index is out of bounds"),
+            LookupError::NotFound => write!(f, "slot is empty"),
+            LookupError::InvalidGeneration => write!(f, "index refers to a different generation"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LookupError {}
+
+/// A snapshot of the size metrics of a vector.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VectorStats {
+    /// The number of elements, see [`GenerationalVector::len`].
+    pub len: usize,
+    /// The number of free slots, see [`GenerationalVector::count_num_free`].
+    pub free: usize,
+    /// The number of slots, see [`GenerationalVector::num_slots`].
+    pub slots: usize,
+    /// The number of slots that can be held without reallocating,
+    /// see [`GenerationalVector::capacity`].
+    pub capacity: usize,
+    /// The fraction of slots that are occupied, or zero if there are no slots.
+    pub utilization: f64,
+}
+
+/// The outcome of [`GenerationalVector::retain_reporting`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RetainReport {
+    /// The number of elements that were removed.
+    pub removed: usize,
+    /// The number of elements that remain in the vector.
+    pub remaining: usize,
+    /// The fraction of slots that are occupied after the removal,
+    /// see [`VectorStats::utilization`].
+    pub resulting_utilization: f64,
+}
+
+/// The error returned when the generation of a slot cannot be advanced any further.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct GenerationOverflow {
+    /// The raw index of the slot whose generation overflowed.
+    pub slot: usize,
+}
+
+impl Display for GenerationOverflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "generation counter of slot {} overflowed", self.slot)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GenerationOverflow {}
+
+/// Determines what happens when the generation of a slot is to be advanced
+/// while it already is at the maximum value of the generation type.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OverflowPolicy {
+    /// Panic when the generation overflows.
+    #[default]
+    Panic,
+    /// Keep the slot at the maximum generation and continue reusing it.
+    /// Stale indices carrying the maximum generation will resolve to new
+    /// elements placed in the slot.
+    Saturate,
+    /// Stop reusing the slot. The slot stays allocated but is never handed
+    /// out again, so stale indices can never resolve.
+    Retire,
+    /// Restart the generation at one. Very old stale indices may resolve to
+    /// new elements placed in the slot.
+    Wrap,
+}
+
+/// Determines how the backing storage grows when an element is pushed
+/// while all slots are in use.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GrowthPolicy {
+    /// Double the capacity, as `Vec` does. Pushing has amortized constant
+    /// cost, but up to half of the allocation may go unused.
+    #[default]
+    Double,
+    /// Grow the capacity by a fixed number of slots. This bounds the unused
+    /// allocation for large vectors, at the cost of reallocating (and
+    /// potentially copying all elements) every time a block is exhausted.
+    FixedBlock(usize),
+}
+
 /// A vector whose elements are addressed by both an index and an entry
 /// generation.
 impl<TEntry, TGeneration> GenerationalVector<TEntry, TGeneration>
@@ -91,6 +261,11 @@ where
         Self {
             data: Default::default(),
             free_list: FreeList::with_capacity(FREE_LIST_CAPACITY),
+            retired: Vec::new(),
+            max_capacity: None,
+            overflow_policy: OverflowPolicy::Panic,
+            growth_policy: GrowthPolicy::Double,
+            tail_generation: TGeneration::one(),
         }
     }
 
@@ -113,6 +288,11 @@ where
         Self {
             data,
             free_list: FreeList::with_capacity(FREE_LIST_CAPACITY),
+            retired: Vec::new(),
+            max_capacity: None,
+            overflow_policy: OverflowPolicy::Panic,
+            growth_policy: GrowthPolicy::Double,
+            tail_generation: TGeneration::one(),
         }
     }
 
@@ -132,6 +312,11 @@ where
                     .map(|entry| GenerationalEntry::new_from_value(entry, TGeneration::one())),
             ),
             free_list: FreeList::with_capacity(FREE_LIST_CAPACITY),
+            retired: Vec::new(),
+            max_capacity: None,
+            overflow_policy: OverflowPolicy::Panic,
+            growth_policy: GrowthPolicy::Double,
+            tail_generation: TGeneration::one(),
         }
     }
 
@@ -146,6 +331,72 @@ where
         Self {
             data: Vec::with_capacity(capacity),
             free_list: FreeList::with_capacity(FREE_LIST_CAPACITY),
+            retired: Vec::new(),
+            max_capacity: None,
+            overflow_policy: OverflowPolicy::Panic,
+            growth_policy: GrowthPolicy::Double,
+            tail_generation: TGeneration::one(),
+        }
+    }
+
+    /// Constructs a new, empty vector with the specified capacity that
+    /// accepts at most `max_live` elements through [`try_push`](Self::try_push).
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::with_max_and_capacity(2, 2);
+    /// assert!(gv.try_push("a").is_ok());
+    /// assert!(gv.try_push("b").is_ok());
+    /// assert_eq!(gv.try_push("c"), Err("c"));
+    /// assert_eq!(gv.capacity(), 2);
+    /// ```
+    pub fn with_max_and_capacity(max_live: usize, capacity: usize) -> Self {
+        Self {
+            max_capacity: Some(max_live),
+            ..Self::with_capacity(capacity)
+        }
+    }
+
+    /// Constructs a new, empty vector that accepts at most `max` live elements
+    /// through [`try_push`](Self::try_push). [`push`](Self::push) is not bounded.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::with_max_capacity(1);
+    /// assert!(gv.try_push("a").is_ok());
+    /// assert_eq!(gv.try_push("b"), Err("b"));
+    ///
+    /// gv.push("b");
+    /// assert_eq!(gv.len(), 2);
+    /// ```
+    pub fn with_max_capacity(max: usize) -> Self {
+        Self {
+            max_capacity: Some(max),
+            ..Self::new()
+        }
+    }
+
+    /// Constructs a new, empty vector with the specified capacities for the
+    /// elements and the free list. Preallocating the free list avoids repeated
+    /// reallocations in workloads that remove many elements, in particular once
+    /// it outgrows the inline buffer of [`FREE_LIST_CAPACITY`] entries.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let gv: GenerationalVector<u32> = GenerationalVector::with_free_list_capacity(10, 100);
+    /// assert!(gv.capacity() >= 10);
+    /// assert!(gv.free_list_capacity() >= 100);
+    /// ```
+    pub fn with_free_list_capacity(data_cap: usize, free_cap: usize) -> Self {
+        Self {
+            free_list: FreeList::with_capacity(free_cap.max(FREE_LIST_CAPACITY)),
+            ..Self::with_capacity(data_cap)
         }
     }
 
@@ -163,7 +414,7 @@ where
     /// ```
     #[inline]
     pub fn len(&self) -> usize {
-        self.data.len() - self.free_list.len()
+        self.data.len() - self.free_list.len() - self.retired.len()
     }
 
     /// Returns `true` if the vector contains no elements.
@@ -206,6 +457,65 @@ where
         self.free_list.len()
     }
 
+    /// Returns the number of slots in the vector, regardless of
+    /// whether they are occupied or free.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut v = generational_vector::GenerationalVector::default();
+    ///
+    /// let a = v.push("a");
+    /// let _b = v.push("b");
+    /// v.remove(a);
+    ///
+    /// assert_eq!(v.len(), 1);
+    /// assert_eq!(v.num_slots(), 2);
+    /// ```
+    #[inline]
+    pub fn num_slots(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Counts the occupied slots within a range of raw slot indices.
+    /// Parts of the range beyond the last slot are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut v = generational_vector::GenerationalVector::default();
+    ///
+    /// let _a = v.push("a");
+    /// let b = v.push("b");
+    /// let _c = v.push("c");
+    /// v.remove(b);
+    ///
+    /// assert_eq!(v.count_occupied(0..2), 1);
+    /// assert_eq!(v.count_occupied(..), v.len());
+    /// ```
+    pub fn count_occupied(&self, range: impl RangeBounds<usize>) -> usize {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.data.len(),
+        }
+        .min(self.data.len());
+
+        if start >= end {
+            return 0;
+        }
+
+        self.data[start..end]
+            .iter()
+            .filter(|entry| entry.entry.is_some())
+            .count()
+    }
+
     /// Returns the number of elements the vector can hold without
     /// reallocating.
     ///
@@ -220,6 +530,86 @@ where
         self.data.capacity()
     }
 
+    /// Returns the strategy for growing the backing storage.
+    pub fn growth(&self) -> GrowthPolicy {
+        self.growth_policy
+    }
+
+    /// Sets the strategy for growing the backing storage when an element is
+    /// pushed while all slots are in use. Defaults to [`GrowthPolicy::Double`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::{GenerationalVector, GrowthPolicy};
+    ///
+    /// let mut v = GenerationalVector::default();
+    /// v.set_growth(GrowthPolicy::FixedBlock(8));
+    ///
+    /// v.push("a");
+    /// assert_eq!(v.capacity(), 8);
+    /// ```
+    pub fn set_growth(&mut self, policy: GrowthPolicy) {
+        self.growth_policy = policy;
+    }
+
+    /// Returns the number of free slots the free list can hold without reallocating.
+    pub fn free_list_capacity(&self) -> usize {
+        self.free_list.capacity()
+    }
+
+    /// Returns the policy applied when a slot's generation overflows.
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+
+    /// Sets the policy applied when a slot's generation overflows, i.e. when
+    /// an element is removed from a slot that already is at the maximum
+    /// generation. Defaults to [`OverflowPolicy::Panic`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::{vector::GenerationalVector, OverflowPolicy};
+    ///
+    /// let mut v = GenerationalVector::<_, u8>::new();
+    /// v.set_overflow_policy(OverflowPolicy::Wrap);
+    ///
+    /// let mut index = v.push("a");
+    /// for _ in 0..u8::MAX {
+    ///     v.remove(index);
+    ///     index = v.push("a");
+    /// }
+    ///
+    /// assert_eq!(v.generation_at(0), Some(1));
+    /// ```
+    pub fn set_overflow_policy(&mut self, policy: OverflowPolicy) {
+        self.overflow_policy = policy;
+    }
+
+    /// Reserves capacity such that the vector can hold at least `target_len`
+    /// elements without reallocating. Free slots are taken into account, since
+    /// they are reused before the vector grows, while retired slots are not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut vec = GenerationalVector::default();
+    /// let a = vec.push(1);
+    /// vec.remove(a);
+    ///
+    /// vec.reserve_to_len(10);
+    /// assert!(vec.capacity() >= 10);
+    /// ```
+    pub fn reserve_to_len(&mut self, target_len: usize) {
+        // Pushing reuses all free slots before appending, but never the retired
+        // ones, so those are required in addition to the target count.
+        let additional = (target_len + self.retired.len()).saturating_sub(self.data.len());
+        self.data.reserve(additional);
+    }
+
     /// Inserts an element into the vector. This method will prefer
     /// replacing empty slots over growing the underlying array.
     ///
@@ -241,10 +631,104 @@ where
         }
     }
 
+    /// Inserts an element into the vector and returns a mutable reference to it
+    /// alongside its index. Like [`push`](Self::push), empty slots are reused
+    /// before the vector grows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::default();
+    ///
+    /// let (a, value) = v.push_mut(1);
+    /// *value += 41;
+    /// assert_eq!(v.get(&a), Some(&42));
+    /// ```
+    pub fn push_mut(&mut self, value: TEntry) -> (GenerationalIndex<TGeneration>, &mut TEntry) {
+        let index = self.push(value);
+        let entry = self.data[index.index]
+            .entry
+            .as_mut()
+            .expect("pushed entry is occupied");
+        (index, entry)
+    }
+
+    /// Inserts an element into the vector unless the vector already holds
+    /// the maximum number of elements it was configured for.
+    /// Like [`push`](Self::push), empty slots are reused before the vector grows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::with_max_and_capacity(1, 1);
+    ///
+    /// let a = v.try_push("a").unwrap();
+    /// assert_eq!(v.try_push("b"), Err("b"));
+    ///
+    /// v.remove(a);
+    /// assert!(v.try_push("b").is_ok());
+    /// ```
+    ///
+    /// ## Returns
+    /// The index of the new element, or the value itself if the vector is full.
+    pub fn try_push(&mut self, value: TEntry) -> Result<GenerationalIndex<TGeneration>, TEntry> {
+        match self.max_capacity {
+            Some(max) if self.len() >= max => Err(value),
+            _ => Ok(self.push(value)),
+        }
+    }
+
+    /// Returns the index of an existing element or pushes a new one.
+    ///
+    /// ## Arguments
+    /// * `maybe` - An optional index of a previously inserted element.
+    /// * `make` - Produces the value to insert if `maybe` does not point
+    ///   to a live element.
+    ///
+    /// ## Returns
+    /// The index of the element and `true` if a new element was created,
+    /// or `false` if `maybe` pointed to an existing element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::default();
+    ///
+    /// let (a, created) = v.get_or_push(None, || "a");
+    /// assert!(created);
+    ///
+    /// let (b, created) = v.get_or_push(Some(&a), || "b");
+    /// assert!(!created);
+    /// assert_eq!(a, b);
+    /// assert_eq!(v.len(), 1);
+    /// ```
+    pub fn get_or_push(
+        &mut self,
+        maybe: Option<&GenerationalIndex<TGeneration>>,
+        make: impl FnOnce() -> TEntry,
+    ) -> (GenerationalIndex<TGeneration>, bool) {
+        match maybe {
+            Some(index) if self.get(index).is_some() => (*index, false),
+            _ => (self.push(make()), true),
+        }
+    }
+
     /// Inserts at the end of the vector.
     #[inline(always)]
     fn insert_tail(&mut self, value: TEntry) -> GenerationalIndex<TGeneration> {
-        let generation = TGeneration::one();
+        if let GrowthPolicy::FixedBlock(block) = self.growth_policy {
+            if self.data.len() == self.data.capacity() {
+                self.data.reserve_exact(block.max(1));
+            }
+        }
+
+        let generation = self.tail_generation;
         let index = GenerationalIndex::new(self.data.len(), generation);
         let gen_entry = GenerationalEntry::new_from_value(value, generation);
         self.data.push(gen_entry);
@@ -288,7 +772,7 @@ where
         match self.data.get(index.index) {
             None => None,
             Some(entry) => {
-                if entry.is_same_gen(&index) {
+                if entry.is_same_gen(index) {
                     entry.entry.as_ref()
                 } else {
                     None
@@ -297,7 +781,13 @@ where
         }
     }
 
-    /// Removes an element from the vector.
+    /// Retrieves a mutable reference to the element at the specified index.
+    ///
+    /// ## Arguments
+    /// * `index` - The index of the element.
+    ///
+    /// ## Returns
+    /// `None` if the element does not exist; `Some` element otherwise.
     ///
     /// # Examples
     ///
@@ -305,82 +795,2615 @@ where
     /// use generational_vector::{GenerationalVector, DeletionResult};
     ///
     /// let mut v = GenerationalVector::default();
-    ///
     /// let a = v.push("a");
     /// let b = v.push("b");
     ///
-    /// assert_eq!(v.remove(a), DeletionResult::Ok);
-    /// assert_eq!(v.remove(b), DeletionResult::Ok);
-    /// assert_eq!(v.remove(b), DeletionResult::NotFound);
-    /// assert_eq!(v.len(), 0);
+    /// *v.get_mut(&a).unwrap() = "A";
+    /// assert_eq!(v.get(&a).unwrap(), &"A");
+    /// assert_eq!(v.get(&b).unwrap(), &"b");
+    ///
+    /// v.remove(b);
+    /// assert_eq!(v.get_mut(&b), None);
     ///
     /// let c = v.push("c");
-    /// assert_eq!(v.remove(b), DeletionResult::InvalidGeneration);
-    /// assert_eq!(v.len(), 1);
+    /// assert_eq!(v.get_mut(&b), None);
+    /// *v.get_mut(&c).unwrap() = "C";
+    /// assert_eq!(v.get(&c).unwrap(), &"C");
     /// ```
-    pub fn remove<T>(&mut self, index: T) -> DeletionResult
+    pub fn get_mut<Index>(&mut self, index: Index) -> Option<&mut TEntry>
     where
-        T: Borrow<GenerationalIndex<TGeneration>>,
+        Index: Borrow<GenerationalIndex<TGeneration>>,
     {
         let index = index.borrow();
-        let ge = &mut self.data[index.index];
 
-        match ge.entry {
-            Some { .. } => {
-                if !ge.is_same_gen(&index) {
-                    return DeletionResult::InvalidGeneration;
+        // Apply boundary check for the index.
+        match self.data.get_mut(index.index) {
+            None => None,
+            Some(entry) => {
+                if entry.is_same_gen(index) {
+                    entry.entry.as_mut()
+                } else {
+                    None
                 }
-
-                ge.reset_and_evolve();
-                self.free_list.push(index.index);
-                DeletionResult::Ok
             }
-            _ => DeletionResult::NotFound,
         }
     }
 
-    /// Produces an immutable enumerator.
+    /// Determines whether the index points to a live element, i.e. whether
+    /// [`get`](Self::get) would return a value.
+    ///
+    /// # Examples
     ///
-    /// ## Examples
     /// ```
     /// use generational_vector::GenerationalVector;
+    /// use generational_vector::vector::GenerationalIndex;
     ///
-    /// let gv: GenerationalVector<_> = vec![20, 30, 40, 50].into();
-    /// let vec: Vec<_> = gv
-    ///     .iter()
-    ///     .filter(|&x| *x > 20 && *x < 50)
-    ///     .map(|x| x * 2)
-    ///     .collect();
+    /// let mut v = GenerationalVector::default();
+    /// let a = v.push("a");
+    /// assert!(v.contains_key(&a));
     ///
-    /// assert_eq!(vec.len(), 2);
-    /// assert!(vec.contains(&60));
-    /// assert!(vec.contains(&80));
-    ///```
-    pub fn iter(&self) -> EntryIterator<TEntry, TGeneration> {
-        self.into_iter()
+    /// // Stale indices are not contained, even if their slot is reused.
+    /// v.remove(&a);
+    /// assert!(!v.contains_key(&a));
+    /// let b = v.push("b");
+    /// assert!(!v.contains_key(&a));
+    /// assert!(v.contains_key(&b));
+    ///
+    /// // Neither are indices beyond the end of the vector.
+    /// let out_of_bounds = GenerationalIndex::from_parts(10, b.generation());
+    /// assert!(!v.contains_key(&out_of_bounds));
+    /// ```
+    pub fn contains_key(&self, index: &GenerationalIndex<TGeneration>) -> bool {
+        match self.data.get(index.index) {
+            Some(entry) => entry.entry.is_some() && entry.is_same_gen(index),
+            None => false,
+        }
     }
 
-    /// Produces a mutable enumerator.
+    /// Retrieves a reference to an element without checking the bounds,
+    /// occupancy or generation of its slot, e.g. for hot loops over indices
+    /// that were already validated.
+    ///
+    /// ## Safety
+    /// The index must point to a live element, i.e. [`contains_key`](Self::contains_key)
+    /// must return `true` for it. Calling this method with any other index is
+    /// *[undefined behavior]* even if the resulting reference is not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
     ///
-    /// ## Examples
     /// ```
     /// use generational_vector::GenerationalVector;
     ///
-    /// let mut gv: GenerationalVector<_> = vec![20, 30, 40, 50].into();
-    /// for value in gv.iter_mut().filter(|&&mut x| x > 20 && x < 50) {
-    ///     *value *= 2;
-    /// }
+    /// let mut v = GenerationalVector::default();
+    /// let a = v.push("a");
     ///
-    /// let vec: Vec<_> = gv.into_iter().collect();
-    /// assert_eq!(vec.len(), 4);
-    /// assert!(vec.contains(&20));
-    /// assert!(vec.contains(&60));
-    /// assert!(vec.contains(&80));
-    /// assert!(vec.contains(&50));
-    ///```
-    pub fn iter_mut(&mut self) -> EntryMutIterator<TEntry, TGeneration> {
-        self.into_iter()
-    }
+    /// assert!(v.contains_key(&a));
+    /// assert_eq!(unsafe { v.get_unchecked(&a) }, &"a");
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked(&self, index: &GenerationalIndex<TGeneration>) -> &TEntry {
+        debug_assert!(
+            self.contains_key(index),
+            "index must point to a live element"
+        );
+        self.data
+            .get_unchecked(index.index)
+            .entry
+            .as_ref()
+            .unwrap_unchecked()
+    }
+
+    /// Retrieves a mutable reference to an element without checking the bounds,
+    /// occupancy or generation of its slot.
+    ///
+    /// ## Safety
+    /// The index must point to a live element, i.e. [`contains_key`](Self::contains_key)
+    /// must return `true` for it. Calling this method with any other index is
+    /// *[undefined behavior]* even if the resulting reference is not used.
+    ///
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::default();
+    /// let a = v.push(1);
+    ///
+    /// unsafe { *v.get_unchecked_mut(&a) += 1 };
+    /// assert_eq!(v.get(&a), Some(&2));
+    /// ```
+    #[inline]
+    pub unsafe fn get_unchecked_mut(
+        &mut self,
+        index: &GenerationalIndex<TGeneration>,
+    ) -> &mut TEntry {
+        debug_assert!(
+            self.contains_key(index),
+            "index must point to a live element"
+        );
+        self.data
+            .get_unchecked_mut(index.index)
+            .entry
+            .as_mut()
+            .unwrap_unchecked()
+    }
+
+    /// Returns the element in the lowest occupied slot along with its index.
+    ///
+    /// ## Returns
+    /// `None` if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::default();
+    /// assert_eq!(v.first(), None);
+    ///
+    /// let a = v.push("a");
+    /// let b = v.push("b");
+    /// v.remove(&a);
+    /// assert_eq!(v.first(), Some((b, &"b")));
+    /// ```
+    pub fn first(&self) -> Option<(GenerationalIndex<TGeneration>, &TEntry)> {
+        self.iter_with_indices().next()
+    }
+
+    /// Returns the element in the highest occupied slot along with its index.
+    ///
+    /// ## Returns
+    /// `None` if the vector is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::default();
+    /// assert_eq!(v.last(), None);
+    ///
+    /// let a = v.push("a");
+    /// let b = v.push("b");
+    /// v.remove(&b);
+    /// assert_eq!(v.last(), Some((a, &"a")));
+    /// ```
+    pub fn last(&self) -> Option<(GenerationalIndex<TGeneration>, &TEntry)> {
+        self.iter_with_indices().next_back()
+    }
+
+    /// Replaces the element at the specified index with a new value.
+    ///
+    /// Unlike removing the element and pushing the new value, the generation
+    /// of the slot does not advance, so the index stays valid and now points
+    /// to the new value.
+    ///
+    /// ## Returns
+    /// `Some` old element if the index is live; `None` otherwise, in which case
+    /// `value` is dropped and the vector is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::default();
+    /// let a = v.push("a");
+    ///
+    /// assert_eq!(v.replace(&a, "A"), Some("a"));
+    /// assert_eq!(v.get(&a), Some(&"A"));
+    ///
+    /// v.remove(&a);
+    /// assert_eq!(v.replace(&a, "B"), None);
+    /// assert!(v.is_empty());
+    /// ```
+    pub fn replace(
+        &mut self,
+        index: &GenerationalIndex<TGeneration>,
+        value: TEntry,
+    ) -> Option<TEntry> {
+        self.get_mut(index)
+            .map(|entry| core::mem::replace(entry, value))
+    }
+
+    /// Heuristically determines whether an index could have originated from
+    /// this vector, i.e. whether its slot exists and its generation does not
+    /// exceed the slot's current generation.
+    ///
+    /// This check cannot prove ownership; it only catches indices that are
+    /// obviously foreign. Use [`get`](Self::get) to test whether an index is live.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::default();
+    /// let a = v.push("a");
+    /// v.remove(&a);
+    ///
+    /// // Stale indices are still plausibly owned.
+    /// assert!(v.owns(&a));
+    ///
+    /// let mut other: GenerationalVector<_> = vec!["x", "y"].into();
+    /// let foreign = other.push("z");
+    /// assert!(!v.owns(&foreign));
+    /// ```
+    pub fn owns(&self, index: &GenerationalIndex<TGeneration>) -> bool
+    where
+        TGeneration: PartialOrd,
+    {
+        match self.data.get(index.index) {
+            None => false,
+            Some(entry) => index.generation <= entry.generation,
+        }
+    }
+
+    /// Retrieves mutable references to the elements in two distinct raw slots.
+    ///
+    /// Unlike index-based accessors, this method addresses slots directly and
+    /// does **not** check generations; it is intended for traversing internal
+    /// structures that are known to be consistent. A slot that was freed and
+    /// reused will silently yield the new element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::default();
+    /// let a = v.push(1);
+    /// let b = v.push(2);
+    ///
+    /// let (x, y) = v.get_slots_mut(0, 1).unwrap();
+    /// core::mem::swap(x, y);
+    ///
+    /// assert_eq!(v.get(&a), Some(&2));
+    /// assert_eq!(v.get(&b), Some(&1));
+    /// assert!(v.get_slots_mut(0, 0).is_none());
+    /// ```
+    ///
+    /// ## Returns
+    /// `None` if the slots are identical, out of range or empty; `Some` pair of
+    /// references otherwise.
+    pub fn get_slots_mut(&mut self, a: usize, b: usize) -> Option<(&mut TEntry, &mut TEntry)> {
+        if a == b || a >= self.data.len() || b >= self.data.len() {
+            return None;
+        }
+
+        let (low, high) = (a.min(b), a.max(b));
+        let (head, tail) = self.data.split_at_mut(high);
+        let low_entry = head[low].entry.as_mut()?;
+        let high_entry = tail[0].entry.as_mut()?;
+
+        if a < b {
+            Some((low_entry, high_entry))
+        } else {
+            Some((high_entry, low_entry))
+        }
+    }
+
+    /// Retrieves mutable references to the elements at two distinct indices.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push(1);
+    /// let b = gv.push(2);
+    ///
+    /// let (x, y) = gv.get_disjoint_mut(&a, &b).unwrap();
+    /// *x += *y;
+    /// assert_eq!(gv.get(&a), Some(&3));
+    /// assert!(gv.get_disjoint_mut(&a, &a).is_none());
+    /// ```
+    ///
+    /// ## Returns
+    /// `None` if either index is invalid or both refer to the same slot;
+    /// `Some` pair of references otherwise.
+    pub fn get_disjoint_mut(
+        &mut self,
+        a: &GenerationalIndex<TGeneration>,
+        b: &GenerationalIndex<TGeneration>,
+    ) -> Option<(&mut TEntry, &mut TEntry)> {
+        self.get(a)?;
+        self.get(b)?;
+        self.get_slots_mut(a.index, b.index)
+    }
+
+    /// Returns the current generation of a raw slot, regardless of
+    /// whether the slot is occupied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::<_, u32>::new();
+    /// let a = v.push("a");
+    /// assert_eq!(v.generation_at(0), Some(1));
+    ///
+    /// v.remove(a);
+    /// assert_eq!(v.generation_at(0), Some(2));
+    /// assert_eq!(v.generation_at(1), None);
+    /// ```
+    ///
+    /// ## Returns
+    /// `None` if the slot does not exist; `Some` generation otherwise.
+    pub fn generation_at(&self, slot: usize) -> Option<TGeneration> {
+        self.data.get(slot).map(|entry| entry.generation)
+    }
+
+    /// Removes an element from the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::{GenerationalVector, DeletionResult};
+    ///
+    /// let mut v = GenerationalVector::default();
+    ///
+    /// let a = v.push("a");
+    /// let b = v.push("b");
+    ///
+    /// assert_eq!(v.remove(a), DeletionResult::Ok);
+    /// assert_eq!(v.remove(b), DeletionResult::Ok);
+    /// assert_eq!(v.remove(b), DeletionResult::NotFound);
+    /// assert_eq!(v.len(), 0);
+    ///
+    /// let c = v.push("c");
+    /// assert_eq!(v.remove(b), DeletionResult::InvalidGeneration);
+    /// assert_eq!(v.len(), 1);
+    /// ```
+    pub fn remove<T>(&mut self, index: T) -> DeletionResult
+    where
+        T: Borrow<GenerationalIndex<TGeneration>>,
+    {
+        let index = index.borrow();
+        let ge = match self.data.get_mut(index.index) {
+            Some(ge) => ge,
+            None => return DeletionResult::NotFound,
+        };
+
+        match ge.entry {
+            Some { .. } => {
+                if !ge.is_same_gen(index) {
+                    return DeletionResult::InvalidGeneration;
+                }
+
+                self.release_slot(index.index);
+                DeletionResult::Ok
+            }
+            _ => DeletionResult::NotFound,
+        }
+    }
+
+    /// Removes an element from the vector and returns it.
+    ///
+    /// ## Returns
+    /// `None` if the slot is empty or belongs to a different generation;
+    /// `Some` removed element otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::default();
+    ///
+    /// let a = v.push("a");
+    /// assert_eq!(v.take(&a), Some("a"));
+    /// assert_eq!(v.take(&a), None);
+    /// assert!(v.is_empty());
+    /// ```
+    pub fn take(&mut self, index: &GenerationalIndex<TGeneration>) -> Option<TEntry> {
+        match self.data.get(index.index) {
+            Some(ge) if ge.entry.is_some() && ge.is_same_gen(index) => {
+                self.release_slot(index.index)
+            }
+            _ => None,
+        }
+    }
+
+    /// Removes an element from the vector **without** advancing the generation
+    /// of its slot.
+    ///
+    /// ## Warning
+    /// This deliberately gives up the protection generational indices provide:
+    /// the index of the removed element stays valid and will resolve to
+    /// whatever element is pushed into the slot next (the ABA problem).
+    /// Only use this if all outstanding indices are discarded or re-derived
+    /// after removal; otherwise use [`remove`](Self::remove).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::{GenerationalVector, DeletionResult};
+    ///
+    /// let mut v = GenerationalVector::default();
+    ///
+    /// let a = v.push("a");
+    /// assert_eq!(v.remove_no_bump(&a), DeletionResult::Ok);
+    /// assert_eq!(v.get(&a), None);
+    ///
+    /// // The stale index now resolves to the new element.
+    /// let b = v.push("b");
+    /// assert_eq!(a, b);
+    /// assert_eq!(v.get(&a), Some(&"b"));
+    /// ```
+    pub fn remove_no_bump(&mut self, index: &GenerationalIndex<TGeneration>) -> DeletionResult {
+        let ge = match self.data.get_mut(index.index) {
+            Some(ge) => ge,
+            None => return DeletionResult::NotFound,
+        };
+
+        match ge.entry {
+            Some { .. } => {
+                if !ge.is_same_gen(index) {
+                    return DeletionResult::InvalidGeneration;
+                }
+
+                ge.entry = None;
+                self.free_list.push(index.index);
+                DeletionResult::Ok
+            }
+            _ => DeletionResult::NotFound,
+        }
+    }
+
+    /// Removes an element from the vector and returns the index of the next
+    /// occupied slot following it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::{GenerationalVector, DeletionResult};
+    ///
+    /// let mut v = GenerationalVector::default();
+    ///
+    /// let a = v.push("a");
+    /// let b = v.push("b");
+    /// let c = v.push("c");
+    /// v.remove(&b);
+    ///
+    /// assert_eq!(v.remove_returning_next_live(&a), (DeletionResult::Ok, Some(c)));
+    /// assert_eq!(v.remove_returning_next_live(&c), (DeletionResult::Ok, None));
+    /// ```
+    ///
+    /// ## Returns
+    /// The result of the deletion and, if the element was deleted, the index
+    /// of the next occupied slot at a higher position, if any.
+    pub fn remove_returning_next_live(
+        &mut self,
+        index: &GenerationalIndex<TGeneration>,
+    ) -> (DeletionResult, Option<GenerationalIndex<TGeneration>>) {
+        let result = self.remove(index);
+        if result != DeletionResult::Ok {
+            return (result, None);
+        }
+
+        let next = self.data[index.index + 1..]
+            .iter()
+            .position(|entry| entry.entry.is_some())
+            .map(|offset| {
+                let slot = index.index + 1 + offset;
+                GenerationalIndex::new(slot, self.data[slot].generation)
+            });
+        (result, next)
+    }
+
+    /// Removes an element and moves the element from the highest occupied
+    /// slot into its place, keeping the occupied slots packed towards the front.
+    /// The removed value is dropped.
+    ///
+    /// The moved element receives a new index; its previous index becomes stale,
+    /// as does the index of the removed element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::default();
+    ///
+    /// let a = v.push("a");
+    /// let b = v.push("b");
+    /// let c = v.push("c");
+    ///
+    /// let moved = v.swap_remove_returning_moved(&a).unwrap();
+    /// assert_eq!(v.get(&moved), Some(&"c"));
+    /// assert_eq!(v.get(&a), None);
+    /// assert_eq!(v.get(&c), None);
+    ///
+    /// // Removing the element in the highest slot does not move anything.
+    /// assert_eq!(v.swap_remove_returning_moved(&b), None);
+    /// assert_eq!(v.get(&moved), Some(&"c"));
+    /// ```
+    ///
+    /// ## Returns
+    /// The new index of the moved element, or `None` if the removed element
+    /// occupied the highest slot or if `index` did not point to a live element.
+    /// Nothing is moved either if the slot of the removed element was retired
+    /// (see [`OverflowPolicy::Retire`]).
+    pub fn swap_remove_returning_moved(
+        &mut self,
+        index: &GenerationalIndex<TGeneration>,
+    ) -> Option<GenerationalIndex<TGeneration>> {
+        self.get(index)?;
+
+        let last = self.data.iter().rposition(|entry| entry.entry.is_some())?;
+        if last == index.index {
+            self.remove(index);
+            return None;
+        }
+
+        self.release_slot(index.index);
+        let position = self
+            .free_list
+            .iter()
+            .rposition(|&slot| slot == index.index)?;
+        self.free_list.remove(position);
+
+        let moved = self.release_slot(last);
+        let ge = &mut self.data[index.index];
+        ge.entry = moved;
+        Some(GenerationalIndex::new(index.index, ge.generation))
+    }
+
+    /// Empties a slot and advances its generation according to the
+    /// overflow policy, then either frees or retires the slot.
+    ///
+    /// ## Returns
+    /// The value previously stored in the slot.
+    fn release_slot(&mut self, slot: usize) -> Option<TEntry> {
+        let policy = self.overflow_policy;
+        let ge = &mut self.data[slot];
+        let value = ge.entry.take();
+        if ge.evolve(policy) {
+            self.free_list.push(slot);
+        } else {
+            self.retired.push(slot);
+        }
+        value
+    }
+
+    /// Removes all empty slots from the end of the vector and returns
+    /// their raw slot indices in ascending order. The removed slots are
+    /// also purged from the free list.
+    ///
+    /// Empty slots that are followed by occupied ones are left untouched,
+    /// as moving them would invalidate existing indices. Retired slots are
+    /// kept as well, since their generation cannot be advanced any further.
+    ///
+    /// Slots appended later start at a generation no lower than that of any
+    /// removed slot, so stale indices to removed slots remain invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut v = GenerationalVector::default();
+    ///
+    /// let a = v.push("a");
+    /// let b = v.push("b");
+    /// let c = v.push("c");
+    ///
+    /// v.remove(&b);
+    /// v.remove(&c);
+    ///
+    /// assert_eq!(v.prune_trailing_free_reporting(), vec![1, 2]);
+    /// assert_eq!(v.num_slots(), 1);
+    /// assert_eq!(v.get(&a), Some(&"a"));
+    ///
+    /// let d = v.push("d");
+    /// assert_ne!(d, b);
+    /// assert_eq!(v.get(&b), None);
+    /// ```
+    pub fn prune_trailing_free_reporting(&mut self) -> Vec<usize>
+    where
+        TGeneration: PartialOrd,
+    {
+        let mut removed = Vec::new();
+        while let Some(last) = self.data.last() {
+            let slot = self.data.len() - 1;
+            if last.entry.is_some() || self.retired.contains(&slot) {
+                break;
+            }
+
+            if last.generation > self.tail_generation {
+                self.tail_generation = last.generation;
+            }
+            self.data.pop();
+            removed.push(slot);
+        }
+
+        if !removed.is_empty() {
+            let num_slots = self.data.len();
+            self.free_list.retain(|index| *index < num_slots);
+            removed.reverse();
+        }
+
+        removed
+    }
+
+    /// Removes all empty slots at the end of the vector and releases the
+    /// unused capacity of the backing storage.
+    ///
+    /// Empty slots that are followed by occupied ones are kept, so the indices
+    /// of the remaining elements stay valid.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::with_capacity(16);
+    /// let a = gv.push("a");
+    /// let b = gv.push("b");
+    /// let c = gv.push("c");
+    ///
+    /// gv.remove(&b);
+    /// gv.remove(&c);
+    /// gv.shrink_to_fit();
+    ///
+    /// assert_eq!(gv.num_slots(), 1);
+    /// assert!(gv.capacity() < 16);
+    /// assert_eq!(gv.get(&a), Some(&"a"));
+    /// ```
+    pub fn shrink_to_fit(&mut self)
+    where
+        TGeneration: PartialOrd,
+    {
+        self.prune_trailing_free_reporting();
+        self.data.shrink_to_fit();
+    }
+
+    /// Moves all elements to the front of the vector, keeping their relative
+    /// order, and drops all trailing empty slots.
+    ///
+    /// Every moved element receives a new index whose generation exceeds both
+    /// its previous generation and that of the slot it moves into, so no index
+    /// issued before compacting resolves to a different element afterwards.
+    /// Callers must rewrite their stored indices using the returned mapping.
+    ///
+    /// Slots whose generation cannot be advanced any further, such as retired
+    /// slots, are skipped and stay empty.
+    ///
+    /// ## Returns
+    /// The old and new index of every element, in ascending slot order.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let b = gv.push("b");
+    /// let c = gv.push("c");
+    /// gv.remove(&b);
+    ///
+    /// let remap = gv.compact();
+    /// assert_eq!(remap.len(), 2);
+    /// assert_eq!(remap[0], (a, a));
+    /// assert_eq!(remap[1].0, c);
+    /// assert_eq!(gv.get(&remap[1].1), Some(&"c"));
+    /// assert_eq!(gv.get(&b), None);
+    /// assert_eq!(gv.get(&c), None);
+    /// assert_eq!(gv.num_slots(), 2);
+    /// ```
+    pub fn compact(
+        &mut self,
+    ) -> Vec<(
+        GenerationalIndex<TGeneration>,
+        GenerationalIndex<TGeneration>,
+    )>
+    where
+        TGeneration: PartialOrd,
+    {
+        let mut remap = Vec::with_capacity(self.len());
+        let mut target = 0;
+        for slot in 0..self.data.len() {
+            if self.data[slot].entry.is_none() {
+                continue;
+            }
+
+            let old = GenerationalIndex::new(slot, self.data[slot].generation);
+
+            // All slots between the target and the current slot are empty.
+            let mut new = old;
+            while target < slot {
+                let own = self.data[slot].generation;
+                let other = self.data[target].generation;
+                let highest = if own > other { own } else { other };
+                if let Some(generation) = highest.checked_add(&TGeneration::one()) {
+                    let value = self.data[slot].entry.take();
+                    self.data[slot].generation = generation;
+                    self.data[target] = GenerationalEntry {
+                        generation,
+                        entry: value,
+                    };
+                    new = GenerationalIndex::new(target, generation);
+                    break;
+                }
+
+                target += 1;
+            }
+
+            remap.push((old, new));
+            target = new.index + 1;
+        }
+
+        self.free_list.clear();
+        for slot in (0..self.data.len()).rev() {
+            if self.data[slot].entry.is_none() && !self.retired.contains(&slot) {
+                self.free_list.push(slot);
+            }
+        }
+
+        self.prune_trailing_free_reporting();
+        remap
+    }
+
+    /// Produces an immutable enumerator.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let gv: GenerationalVector<_> = vec![20, 30, 40, 50].into();
+    /// let vec: Vec<_> = gv
+    ///     .iter()
+    ///     .filter(|&x| *x > 20 && *x < 50)
+    ///     .map(|x| x * 2)
+    ///     .collect();
+    ///
+    /// assert_eq!(vec.len(), 2);
+    /// assert!(vec.contains(&60));
+    /// assert!(vec.contains(&80));
+    ///```
+    pub fn iter(&self) -> EntryIterator<'_, TEntry, TGeneration> {
+        self.into_iter()
+    }
+
+    /// Produces a mutable enumerator.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv: GenerationalVector<_> = vec![20, 30, 40, 50].into();
+    /// for value in gv.iter_mut().filter(|&&mut x| x > 20 && x < 50) {
+    ///     *value *= 2;
+    /// }
+    ///
+    /// let vec: Vec<_> = gv.into_iter().collect();
+    /// assert_eq!(vec.len(), 4);
+    /// assert!(vec.contains(&20));
+    /// assert!(vec.contains(&60));
+    /// assert!(vec.contains(&80));
+    /// assert!(vec.contains(&50));
+    ///```
+    pub fn iter_mut(&mut self) -> EntryMutIterator<'_, TEntry, TGeneration> {
+        self.into_iter()
+    }
+
+    /// Produces an immutable enumerator that also yields the index of each element.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let b = gv.push("b");
+    ///
+    /// let pairs: Vec<_> = gv.iter_with_indices().collect();
+    /// assert_eq!(pairs, vec![(a, &"a"), (b, &"b")]);
+    ///```
+    pub fn iter_with_indices(&self) -> IndexedEntryIterator<'_, TEntry, TGeneration> {
+        self.iter().indexed()
+    }
+
+    /// Produces a mutable enumerator that also yields the index of each element.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push(1);
+    ///
+    /// for (index, value) in gv.iter_mut_with_indices() {
+    ///     assert_eq!(index, a);
+    ///     *value += 1;
+    /// }
+    /// assert_eq!(gv.get(&a), Some(&2));
+    ///```
+    pub fn iter_mut_with_indices(&mut self) -> IndexedEntryMutIterator<'_, TEntry, TGeneration> {
+        self.iter_mut().indexed()
+    }
+
+    /// Produces an enumerator over all slots of the vector, including empty ones.
+    ///
+    /// Each item consists of the raw slot index, the slot's current
+    /// generation and the value stored in it, if any.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let _b = gv.push("b");
+    /// gv.remove(&a);
+    ///
+    /// let slots: Vec<_> = gv.slot_values().map(|(slot, _, value)| (slot, value)).collect();
+    /// assert_eq!(slots, vec![(0, None), (1, Some(&"b"))]);
+    /// ```
+    pub fn slot_values(&self) -> impl Iterator<Item = (usize, TGeneration, Option<&TEntry>)> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(slot, entry)| (slot, entry.generation, entry.entry.as_ref()))
+    }
+
+    /// Produces an enumerator over the occupied slots of the vector.
+    ///
+    /// Each item consists of the raw slot index, the slot's current
+    /// generation and a reference to the value stored in it.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::<_, u32>::new();
+    /// let a = gv.push("a");
+    /// let _b = gv.push("b");
+    /// gv.remove(&a);
+    ///
+    /// let live: Vec<_> = gv.iter_live_full().collect();
+    /// assert_eq!(live, vec![(1, 1, &"b")]);
+    /// ```
+    pub fn iter_live_full(&self) -> impl Iterator<Item = (usize, TGeneration, &TEntry)> + '_ {
+        self.data.iter().enumerate().filter_map(|(slot, entry)| {
+            entry
+                .entry
+                .as_ref()
+                .map(|value| (slot, entry.generation, value))
+        })
+    }
+
+    /// Clones the elements of the vector into a `Vec` sorted by the specified
+    /// comparison. The vector itself and all indices remain unchanged.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let gv: GenerationalVector<_> = vec![3, 1, 2].into();
+    /// assert_eq!(gv.to_sorted_vec_by(|a, b| a.cmp(b)), vec![1, 2, 3]);
+    /// ```
+    pub fn to_sorted_vec_by(&self, cmp: impl FnMut(&TEntry, &TEntry) -> Ordering) -> Vec<TEntry>
+    where
+        TEntry: Clone,
+    {
+        let mut values: Vec<_> = self.iter().cloned().collect();
+        values.sort_by(cmp);
+        values
+    }
+
+    /// Folds all elements of the vector along with their indices into a
+    /// single value, visiting the elements in ascending slot order.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let _a = gv.push(1);
+    /// let b = gv.push(5);
+    /// let _c = gv.push(3);
+    ///
+    /// let max = gv.fold_indexed(None, |max, index, &value| match max {
+    ///     Some((_, best)) if best >= value => max,
+    ///     _ => Some((index, value)),
+    /// });
+    /// assert_eq!(max, Some((b, 5)));
+    /// ```
+    pub fn fold_indexed<B>(
+        &self,
+        init: B,
+        mut f: impl FnMut(B, GenerationalIndex<TGeneration>, &TEntry) -> B,
+    ) -> B {
+        self.data
+            .iter()
+            .enumerate()
+            .fold(init, |acc, (slot, entry)| match entry.entry.as_ref() {
+                Some(value) => f(acc, GenerationalIndex::new(slot, entry.generation), value),
+                None => acc,
+            })
+    }
+
+    /// Removes all elements from the vector while keeping its allocation.
+    ///
+    /// All slots are kept and their generations advanced, so that every
+    /// previously issued index becomes stale.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    ///
+    /// gv.clear();
+    /// assert!(gv.is_empty());
+    /// assert_eq!(gv.get(&a), None);
+    /// ```
+    pub fn clear(&mut self) {
+        for slot in 0..self.data.len() {
+            if self.data[slot].entry.is_some() {
+                self.release_slot(slot);
+            }
+        }
+    }
+
+    /// Removes all elements from the vector and ensures that it can hold at
+    /// least `target_capacity` elements without reallocating.
+    ///
+    /// All slots are kept and their generations advanced, so that every
+    /// previously issued index becomes stale.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    ///
+    /// gv.clear_and_reserve(32);
+    /// assert!(gv.is_empty());
+    /// assert_eq!(gv.get(&a), None);
+    /// assert!(gv.capacity() >= 32);
+    /// ```
+    pub fn clear_and_reserve(&mut self, target_capacity: usize) {
+        self.clear();
+        self.data
+            .reserve(target_capacity.saturating_sub(self.data.len()));
+    }
+
+    /// Determines how many generations an index lags behind the current
+    /// generation of its slot. A delta of zero means the index refers to the
+    /// slot's current generation.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::<_, u32>::new();
+    /// let a = gv.push("a");
+    /// assert_eq!(gv.generation_delta(&a), Some(0));
+    ///
+    /// gv.remove(&a);
+    /// assert_eq!(gv.generation_delta(&a), Some(1));
+    /// ```
+    ///
+    /// ## Returns
+    /// `None` if the slot does not exist or if the index's generation is
+    /// ahead of the slot's; `Some` delta otherwise. The delta is a plain count,
+    /// since generation types such as [`DefaultGenerationType`] cannot represent zero.
+    pub fn generation_delta(&self, index: &GenerationalIndex<TGeneration>) -> Option<u128>
+    where
+        TGeneration: ToPrimitive,
+    {
+        let entry = self.data.get(index.index)?;
+        entry
+            .generation
+            .as_u128()
+            .checked_sub(index.generation.as_u128())
+    }
+
+    /// Keeps the `n` elements in the lowest slots and removes all others.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv: GenerationalVector<_> = vec!["a", "b", "c"].into();
+    /// assert_eq!(gv.retain_first_n(1), 2);
+    /// assert_eq!(gv.iter().collect::<Vec<_>>(), vec![&"a"]);
+    /// ```
+    ///
+    /// ## Returns
+    /// The number of removed elements.
+    pub fn retain_first_n(&mut self, n: usize) -> usize {
+        let mut kept = 0;
+        let mut removed = 0;
+        for slot in 0..self.data.len() {
+            if self.data[slot].entry.is_none() {
+                continue;
+            }
+
+            if kept < n {
+                kept += 1;
+            } else {
+                self.release_slot(slot);
+                removed += 1;
+            }
+        }
+
+        removed
+    }
+
+    /// Inserts all elements of an iterator, reserving the required capacity
+    /// up front so that the vector reallocates at most once. Like
+    /// [`push`](Self::push), empty slots are reused before the vector grows.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let indices = gv.extend_reserving(["a", "b"].into_iter());
+    ///
+    /// assert_eq!(gv.get(&indices[0]), Some(&"a"));
+    /// assert_eq!(gv.get(&indices[1]), Some(&"b"));
+    /// ```
+    ///
+    /// ## Returns
+    /// The indices of the inserted elements, in iteration order.
+    pub fn extend_reserving(
+        &mut self,
+        iter: impl ExactSizeIterator<Item = TEntry>,
+    ) -> Vec<GenerationalIndex<TGeneration>> {
+        let additional = iter.len().saturating_sub(self.free_list.len());
+        self.data.reserve(additional);
+        iter.map(|value| self.push(value)).collect()
+    }
+
+    /// Retrieves mutable references to the elements at multiple distinct indices.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push(1);
+    /// let b = gv.push(2);
+    ///
+    /// for value in gv.get_disjoint_slice_mut(&[b, a]).unwrap() {
+    ///     *value *= 10;
+    /// }
+    /// assert_eq!(gv.get(&a), Some(&10));
+    /// assert_eq!(gv.get(&b), Some(&20));
+    ///
+    /// assert!(gv.get_disjoint_slice_mut(&[a, a]).is_none());
+    /// ```
+    ///
+    /// ## Returns
+    /// `None` if any index does not point to a live element or if two indices
+    /// refer to the same slot; otherwise the references in input order.
+    pub fn get_disjoint_slice_mut(
+        &mut self,
+        indices: &[GenerationalIndex<TGeneration>],
+    ) -> Option<Vec<&mut TEntry>> {
+        if indices.iter().any(|index| self.get(index).is_none()) {
+            return None;
+        }
+
+        let mut slots: Vec<_> = indices.iter().map(|index| index.index).collect();
+        slots.sort_unstable();
+        if slots.windows(2).any(|pair| pair[0] == pair[1]) {
+            return None;
+        }
+
+        // All slots are occupied and pairwise distinct, hence the references never alias.
+        let ptr = self.data.as_mut_ptr();
+        Some(
+            indices
+                .iter()
+                .map(|index| {
+                    let element = unsafe { &mut *ptr.add(index.index) };
+                    element
+                        .entry
+                        .as_mut()
+                        .expect("slot was checked to be occupied")
+                })
+                .collect(),
+        )
+    }
+
+    /// Advances the generation of an element's slot while keeping the element,
+    /// invalidating all outstanding indices to it.
+    ///
+    /// If the generation is at its maximum, the vector's [`OverflowPolicy`]
+    /// applies; under [`OverflowPolicy::Saturate`] and [`OverflowPolicy::Retire`]
+    /// the element and its index are left untouched.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    ///
+    /// let b = gv.touch(&a).unwrap();
+    /// assert_eq!(gv.get(&a), None);
+    /// assert_eq!(gv.get(&b), Some(&"a"));
+    /// ```
+    ///
+    /// ## Returns
+    /// The new index of the element, or `None` if `index` did not point to
+    /// a live element or the generation could not be advanced.
+    pub fn touch(
+        &mut self,
+        index: &GenerationalIndex<TGeneration>,
+    ) -> Option<GenerationalIndex<TGeneration>> {
+        self.get(index)?;
+
+        let ge = &mut self.data[index.index];
+        // A saturated generation does not change and would keep `index` valid.
+        if !ge.evolve(self.overflow_policy) || ge.is_same_gen(index) {
+            return None;
+        }
+
+        Some(GenerationalIndex::new(index.index, ge.generation))
+    }
+
+    /// Collects the size metrics of the vector in one call.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::with_capacity(8);
+    /// let a = gv.push("a");
+    /// let _b = gv.push("b");
+    /// gv.remove(a);
+    ///
+    /// let stats = gv.stats();
+    /// assert_eq!(stats.len, 1);
+    /// assert_eq!(stats.free, 1);
+    /// assert_eq!(stats.slots, 2);
+    /// assert_eq!(stats.capacity, 8);
+    /// assert_eq!(stats.utilization, 0.5);
+    /// ```
+    pub fn stats(&self) -> VectorStats {
+        let len = self.len();
+        let slots = self.num_slots();
+        VectorStats {
+            len,
+            free: self.count_num_free(),
+            slots,
+            capacity: self.capacity(),
+            utilization: if slots == 0 {
+                0.0
+            } else {
+                len as f64 / slots as f64
+            },
+        }
+    }
+
+    /// Removes a batch of elements whose indices are sorted by ascending slot.
+    /// Compared to calling [`remove`](Self::remove) repeatedly, the free list
+    /// is grown only once for the whole batch.
+    ///
+    /// Indices that are stale, already removed or beyond the last slot are skipped.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let b = gv.push("b");
+    /// let c = gv.push("c");
+    ///
+    /// assert_eq!(gv.remove_sorted(&[a, c, c]), 2);
+    /// assert_eq!(gv.len(), 1);
+    /// assert_eq!(gv.get(&b), Some(&"b"));
+    /// ```
+    ///
+    /// ## Returns
+    /// The number of removed elements.
+    pub fn remove_sorted(&mut self, handles: &[GenerationalIndex<TGeneration>]) -> usize {
+        debug_assert!(
+            handles
+                .windows(2)
+                .all(|pair| pair[0].index <= pair[1].index),
+            "indices must be sorted by ascending slot"
+        );
+
+        let policy = self.overflow_policy;
+        self.free_list.reserve(handles.len());
+
+        let mut removed = 0;
+        for index in handles {
+            let ge = match self.data.get_mut(index.index) {
+                Some(ge) if ge.entry.is_some() && ge.is_same_gen(index) => ge,
+                _ => continue,
+            };
+
+            ge.entry = None;
+            if ge.evolve(policy) {
+                self.free_list.push(index.index);
+            } else {
+                self.retired.push(index.index);
+            }
+            removed += 1;
+        }
+
+        removed
+    }
+
+    /// Removes all elements whose indices are not contained in `keep`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    /// use std::collections::HashSet;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let b = gv.push("b");
+    ///
+    /// let keep = HashSet::from([a]);
+    /// assert_eq!(gv.retain_handles_returning_removed(&keep), vec![(b, "b")]);
+    /// assert_eq!(gv.len(), 1);
+    /// ```
+    ///
+    /// ## Returns
+    /// The indices the removed elements had before removal and the removed
+    /// values, in ascending slot order.
+    #[cfg(feature = "std")]
+    pub fn retain_handles_returning_removed(
+        &mut self,
+        keep: &HashSet<GenerationalIndex<TGeneration>>,
+    ) -> Vec<(GenerationalIndex<TGeneration>, TEntry)>
+    where
+        TGeneration: Hash + Eq,
+    {
+        let mut removed = Vec::new();
+        for slot in 0..self.data.len() {
+            let ge = &self.data[slot];
+            if ge.entry.is_none() {
+                continue;
+            }
+
+            let index = GenerationalIndex::new(slot, ge.generation);
+            if !keep.contains(&index) {
+                if let Some(value) = self.release_slot(slot) {
+                    removed.push((index, value));
+                }
+            }
+        }
+
+        removed
+    }
+
+    /// Appends `count` empty slots to the vector and adds them to the free list.
+    /// Subsequent pushes fill these slots in ascending order before any
+    /// previously freed slots are reused.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let _a = gv.push("a");
+    ///
+    /// assert_eq!(gv.grow_with_empty(2), 1..3);
+    /// assert_eq!(gv.len(), 1);
+    /// assert_eq!(gv.num_slots(), 3);
+    /// ```
+    ///
+    /// ## Returns
+    /// The range of raw slot indices that were created.
+    pub fn grow_with_empty(&mut self, count: usize) -> Range<usize> {
+        let range = self.data.len()..self.data.len() + count;
+        self.data.reserve(count);
+        self.data.extend(
+            range
+                .clone()
+                .map(|_| GenerationalEntry::new_empty(self.tail_generation)),
+        );
+        self.free_list.extend(range.clone().rev());
+        range
+    }
+
+    /// Compares the generation of an index to an epoch value, e.g. to
+    /// determine whether an index predates an epoch shared across vectors.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::vector::GenerationalVector;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut gv = GenerationalVector::<_, u32>::new();
+    /// let a = gv.push("a");
+    ///
+    /// assert_eq!(GenerationalVector::<&str, u32>::handle_generation_cmp(&a, 2), Ordering::Less);
+    /// ```
+    pub fn handle_generation_cmp(
+        index: &GenerationalIndex<TGeneration>,
+        epoch: TGeneration,
+    ) -> Ordering
+    where
+        TGeneration: Ord,
+    {
+        index.generation.cmp(&epoch)
+    }
+
+    /// Returns all slots retired under [`OverflowPolicy::Retire`] to the free
+    /// list, restarting their generation at one.
+    ///
+    /// ## Warning
+    /// Indices that were issued for a reclaimed slot before it was retired
+    /// may resolve to new elements placed in the slot (the ABA problem).
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::{vector::GenerationalVector, OverflowPolicy};
+    ///
+    /// let mut gv = GenerationalVector::<_, u8>::new();
+    /// gv.set_overflow_policy(OverflowPolicy::Retire);
+    ///
+    /// let mut index = gv.push("a");
+    /// for _ in 1..u8::MAX {
+    ///     gv.remove(index);
+    ///     index = gv.push("a");
+    /// }
+    ///
+    /// // The slot is retired on removal and no longer reused.
+    /// gv.remove(index);
+    /// assert_eq!(gv.count_num_free(), 0);
+    ///
+    /// assert_eq!(gv.reclaim_retired(), 1);
+    /// assert_eq!(gv.count_num_free(), 1);
+    /// ```
+    ///
+    /// ## Returns
+    /// The number of reclaimed slots.
+    pub fn reclaim_retired(&mut self) -> usize {
+        let count = self.retired.len();
+        for slot in self.retired.drain(..) {
+            self.data[slot].generation = TGeneration::one();
+            self.free_list.push(slot);
+        }
+        count
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements,
+    /// taking free slots into account since they are reused before the
+    /// vector grows.
+    ///
+    /// ## Errors
+    /// Returns an error if the capacity overflows or the allocator reports a failure.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv: GenerationalVector<u32> = GenerationalVector::new();
+    /// gv.try_reserve(10).expect("out of memory");
+    /// assert!(gv.capacity() >= 10);
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.data
+            .try_reserve(additional.saturating_sub(self.free_list.len()))
+    }
+
+    /// Gets the value at the specified index, panicking with a descriptive
+    /// message if the index is invalid.
+    ///
+    /// ## Panics
+    /// Panics with `msg`, followed by the slot and generation of the index,
+    /// if the index does not refer to a live value.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// assert_eq!(gv.expect(&a, "value a should exist"), &"a");
+    /// ```
+    #[track_caller]
+    pub fn expect(&self, index: &GenerationalIndex<TGeneration>, msg: &str) -> &TEntry
+    where
+        TGeneration: Debug,
+    {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "{}: no value at slot {} with generation {:?}",
+                msg, index.index, index.generation
+            ),
+        }
+    }
+
+    /// Returns the generation following the specified one, saturating at
+    /// [`GenerationType::max_generation`] instead of wrapping or panicking.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::vector::GenerationalVector;
+    ///
+    /// type Gv = GenerationalVector<(), u8>;
+    /// assert_eq!(Gv::saturating_next_generation(1), 2);
+    /// assert_eq!(Gv::saturating_next_generation(255), 255);
+    /// ```
+    pub fn saturating_next_generation(generation: TGeneration) -> TGeneration {
+        generation
+            .checked_add(&TGeneration::one())
+            .unwrap_or_else(TGeneration::max_generation)
+    }
+
+    /// Iterates the live values together with their indices and the element
+    /// at the same slot in a parallel slice. Slots outside the bounds of
+    /// `other` are skipped.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let b = gv.push("b");
+    /// let weights = [1.0, 2.0];
+    ///
+    /// let zipped: Vec<_> = gv.iter_zipped(&weights).collect();
+    /// assert_eq!(zipped, vec![(a, &"a", &1.0), (b, &"b", &2.0)]);
+    /// ```
+    pub fn iter_zipped<'a, U>(
+        &'a self,
+        other: &'a [U],
+    ) -> impl Iterator<Item = (GenerationalIndex<TGeneration>, &'a TEntry, &'a U)> + 'a {
+        self.data
+            .iter()
+            .zip(other)
+            .enumerate()
+            .filter_map(|(slot, (entry, item))| {
+                entry
+                    .entry
+                    .as_ref()
+                    .map(|value| (GenerationalIndex::new(slot, entry.generation), value, item))
+            })
+    }
+
+    /// Splits the vector into its slot layout and its live values.
+    ///
+    /// ## Returns
+    /// The `(generation, occupied)` pair of every slot, and the live values in
+    /// slot order. Use [`from_layout_and_values`](Self::from_layout_and_values)
+    /// to reconstruct the vector.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::<_, u32>::new();
+    /// let a = gv.push("a");
+    /// let _b = gv.push("b");
+    /// gv.remove(&a);
+    ///
+    /// let (layout, values) = gv.into_layout_and_values();
+    /// assert_eq!(layout, vec![(2, false), (1, true)]);
+    /// assert_eq!(values, vec!["b"]);
+    /// ```
+    pub fn into_layout_and_values(self) -> (Vec<(TGeneration, bool)>, Vec<TEntry>) {
+        let mut layout = Vec::with_capacity(self.data.len());
+        let mut values = Vec::with_capacity(self.len());
+        for entry in self.data {
+            layout.push((entry.generation, entry.entry.is_some()));
+            values.extend(entry.entry);
+        }
+
+        (layout, values)
+    }
+
+    /// Reconstructs a vector from the output of
+    /// [`into_layout_and_values`](Self::into_layout_and_values). Indices
+    /// issued by the original vector remain valid. Slots that were retired
+    /// in the original vector are restored as free slots. Slots appended
+    /// later start after the highest generation in the layout, so that
+    /// indices to slots trimmed from the original vector stay stale.
+    ///
+    /// ## Returns
+    /// `None` if the number of values does not match the number of occupied slots.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::<_, u32>::new();
+    /// let a = gv.push("a");
+    /// let b = gv.push("b");
+    /// gv.remove(&a);
+    ///
+    /// let (layout, values) = gv.into_layout_and_values();
+    /// let gv = GenerationalVector::from_layout_and_values(layout, values).unwrap();
+    /// assert_eq!(gv.get(&a), None);
+    /// assert_eq!(gv.get(&b), Some(&"b"));
+    /// ```
+    pub fn from_layout_and_values(
+        layout: Vec<(TGeneration, bool)>,
+        values: Vec<TEntry>,
+    ) -> Option<Self>
+    where
+        TGeneration: PartialOrd,
+    {
+        if layout.iter().filter(|(_, occupied)| *occupied).count() != values.len() {
+            return None;
+        }
+
+        let mut gv = Self::with_capacity(layout.len());
+        let mut values = values.into_iter();
+        for (slot, (generation, occupied)) in layout.into_iter().enumerate() {
+            if generation >= gv.tail_generation {
+                gv.tail_generation = generation
+                    .checked_add(&TGeneration::one())
+                    .unwrap_or_else(TGeneration::max_generation);
+            }
+            if occupied {
+                let value = values.next()?;
+                gv.data
+                    .push(GenerationalEntry::new_from_value(value, generation));
+            } else {
+                gv.data.push(GenerationalEntry::new_empty(generation));
+                gv.free_list.push(slot);
+            }
+        }
+
+        // Reuse the lowest free slots first.
+        gv.free_list.reverse();
+        Some(gv)
+    }
+
+    /// Retains only the elements for which the predicate returns `true`,
+    /// analogous to [`Vec::retain`]. The generations of removed elements are
+    /// advanced, while the retained elements keep their indices.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push(1);
+    /// let b = gv.push(2);
+    ///
+    /// gv.retain(|&value| value % 2 == 0);
+    /// assert_eq!(gv.get(&a), None);
+    /// assert_eq!(gv.get(&b), Some(&2));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&TEntry) -> bool,
+    {
+        self.retain_mut_indexed(|_, value| f(value));
+    }
+
+    /// Retains only the elements for which the predicate returns `true`,
+    /// passing each element's index and a mutable reference to its value.
+    /// The generations of removed elements are advanced.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push(1);
+    /// let b = gv.push(2);
+    ///
+    /// gv.retain_mut_indexed(|index, value| {
+    ///     *value *= 10;
+    ///     index != b
+    /// });
+    ///
+    /// assert_eq!(gv.get(&a), Some(&10));
+    /// assert_eq!(gv.get(&b), None);
+    /// ```
+    pub fn retain_mut_indexed(
+        &mut self,
+        mut f: impl FnMut(GenerationalIndex<TGeneration>, &mut TEntry) -> bool,
+    ) {
+        for slot in 0..self.data.len() {
+            let ge = &mut self.data[slot];
+            let generation = ge.generation;
+            let keep = match ge.entry.as_mut() {
+                None => continue,
+                Some(value) => f(GenerationalIndex::new(slot, generation), value),
+            };
+
+            if !keep {
+                self.release_slot(slot);
+            }
+        }
+    }
+
+    /// Iterates the indices of all live elements in slot order.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let b = gv.push("b");
+    ///
+    /// assert_eq!(gv.keys().collect::<Vec<_>>(), vec![a, b]);
+    /// ```
+    pub fn keys(&self) -> Keys<'_, TEntry, TGeneration> {
+        Keys { inner: self.iter() }
+    }
+
+    /// Advances the generation of every slot, invalidating all outstanding
+    /// indices while keeping the values in place. Retired slots are left untouched.
+    ///
+    /// ## Errors
+    /// Returns the first slot whose generation cannot be advanced any further,
+    /// in which case no generation is changed.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    ///
+    /// gv.bump_all_generations().unwrap();
+    /// assert_eq!(gv.get(&a), None);
+    /// assert_eq!(gv.iter().collect::<Vec<_>>(), vec![&"a"]);
+    /// ```
+    pub fn bump_all_generations(&mut self) -> Result<(), GenerationOverflow> {
+        let mut generations = Vec::with_capacity(self.data.len());
+        for (slot, entry) in self.data.iter().enumerate() {
+            if self.retired.contains(&slot) {
+                generations.push(entry.generation);
+                continue;
+            }
+
+            match entry.generation.checked_add(&TGeneration::one()) {
+                Some(generation) => generations.push(generation),
+                None => return Err(GenerationOverflow { slot }),
+            }
+        }
+
+        for (entry, generation) in self.data.iter_mut().zip(generations) {
+            entry.generation = generation;
+        }
+
+        Ok(())
+    }
+
+    /// Gets the value at the specified index, reporting why the lookup failed
+    /// if the index does not refer to a live value.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::{GenerationalVector, LookupError};
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// assert_eq!(gv.try_get(&a), Ok(&"a"));
+    ///
+    /// gv.remove(&a);
+    /// assert_eq!(gv.try_get(&a), Err(LookupError::InvalidGeneration));
+    /// ```
+    pub fn try_get(&self, index: &GenerationalIndex<TGeneration>) -> Result<&TEntry, LookupError> {
+        let entry = self.data.get(index.index).ok_or(LookupError::OutOfBounds)?;
+        if !entry.is_same_gen(index) {
+            return Err(LookupError::InvalidGeneration);
+        }
+
+        entry.entry.as_ref().ok_or(LookupError::NotFound)
+    }
+
+    /// Checks a batch of indices, e.g. before acting on externally supplied indices.
+    ///
+    /// ## Returns
+    /// For each index, `Ok(())` if it refers to a live value or the reason
+    /// it does not, as reported by [`try_get`](Self::try_get).
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::{GenerationalVector, LookupError};
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let b = gv.push("b");
+    /// gv.remove(&b);
+    ///
+    /// assert_eq!(
+    ///     gv.validate_handles(&[a, b]),
+    ///     vec![Ok(()), Err(LookupError::InvalidGeneration)]
+    /// );
+    /// ```
+    pub fn validate_handles(
+        &self,
+        handles: &[GenerationalIndex<TGeneration>],
+    ) -> Vec<Result<(), LookupError>> {
+        handles
+            .iter()
+            .map(|index| self.try_get(index).map(|_| ()))
+            .collect()
+    }
+
+    /// Removes an element from the vector and returns it **without** advancing
+    /// the generation of its slot, so that it can be put back under the same
+    /// index using [`reinsert`](Self::reinsert).
+    ///
+    /// Like [`remove_no_bump`](Self::remove_no_bump), the index stays valid and
+    /// resolves to whatever element is pushed into the slot next.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    ///
+    /// let value = gv.take_without_bump(&a).unwrap();
+    /// assert_eq!(gv.get(&a), None);
+    ///
+    /// gv.reinsert(&a, value).unwrap();
+    /// assert_eq!(gv.get(&a), Some(&"a"));
+    /// ```
+    pub fn take_without_bump(&mut self, index: &GenerationalIndex<TGeneration>) -> Option<TEntry> {
+        let ge = self.data.get_mut(index.index)?;
+        if !ge.is_same_gen(index) {
+            return None;
+        }
+
+        let value = ge.entry.take()?;
+        self.free_list.push(index.index);
+        Some(value)
+    }
+
+    /// Places a value into the exact slot and generation of the specified index,
+    /// provided that the slot is still free and has not moved on to a different
+    /// generation, e.g. after [`take_without_bump`](Self::take_without_bump).
+    ///
+    /// ## Returns
+    /// `Err` containing the value if the slot is not available.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let value = gv.take_without_bump(&a).unwrap();
+    ///
+    /// let _b = gv.push("b");
+    /// assert_eq!(gv.reinsert(&a, value), Err("a"));
+    /// ```
+    pub fn reinsert(
+        &mut self,
+        index: &GenerationalIndex<TGeneration>,
+        value: TEntry,
+    ) -> Result<(), TEntry> {
+        match self.data.get(index.index) {
+            Some(ge) if ge.entry.is_none() && ge.is_same_gen(index) => {}
+            _ => return Err(value),
+        }
+
+        match self.free_list.iter().rposition(|&slot| slot == index.index) {
+            Some(position) => {
+                self.free_list.remove(position);
+                self.data[index.index].entry = Some(value);
+                Ok(())
+            }
+            // Retired slots are never handed out again.
+            None => Err(value),
+        }
+    }
+
+    /// Consumes the vector and leaks it, returning a mutable reference that
+    /// lives for the remainder of the program, analogous to [`Box::leak`].
+    /// This is useful for process-lifetime arenas.
+    ///
+    /// The vector and its elements are intentionally never freed.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let gv: &'static mut GenerationalVector<&str> = GenerationalVector::default().leak();
+    /// let a = gv.push("a");
+    /// assert_eq!(gv.get(&a), Some(&"a"));
+    /// ```
+    pub fn leak(self) -> &'static mut GenerationalVector<TEntry, TGeneration>
+    where
+        TEntry: 'static,
+        TGeneration: 'static,
+    {
+        Box::leak(Box::new(self))
+    }
+
+    /// Retains only the elements for which the predicate returns `true` and
+    /// reports the outcome, e.g. for logging garbage collection metrics.
+    /// The generations of removed elements are advanced.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv: GenerationalVector<_> = vec![1, 2, 3, 4].into();
+    /// let report = gv.retain_reporting(|&value| value > 3);
+    ///
+    /// assert_eq!(report.removed, 3);
+    /// assert_eq!(report.remaining, 1);
+    /// assert_eq!(report.resulting_utilization, 0.25);
+    /// ```
+    pub fn retain_reporting(&mut self, mut f: impl FnMut(&TEntry) -> bool) -> RetainReport {
+        let mut removed = 0;
+        self.retain(|value| {
+            let keep = f(value);
+            if !keep {
+                removed += 1;
+            }
+            keep
+        });
+
+        let stats = self.stats();
+        RetainReport {
+            removed,
+            remaining: stats.len,
+            resulting_utilization: stats.utilization,
+        }
+    }
+
+    /// Returns a mutable reference to the value in the specified raw slot,
+    /// inserting the value produced by `make` if the slot is empty. The vector
+    /// is grown with empty slots as needed to reach `slot`.
+    ///
+    /// ## Panics
+    /// Panics if the slot has been retired due to a generation overflow.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// *gv.get_mut_or_insert_at(2, || 0) += 1;
+    /// *gv.get_mut_or_insert_at(2, || 0) += 1;
+    ///
+    /// assert_eq!(gv.num_slots(), 3);
+    /// assert_eq!(gv.iter().collect::<Vec<_>>(), vec![&2]);
+    /// ```
+    pub fn get_mut_or_insert_at(
+        &mut self,
+        slot: usize,
+        make: impl FnOnce() -> TEntry,
+    ) -> &mut TEntry {
+        if slot >= self.data.len() {
+            self.grow_with_empty(slot + 1 - self.data.len());
+        }
+
+        if self.data[slot].entry.is_none() {
+            let position = self
+                .free_list
+                .iter()
+                .rposition(|&free| free == slot)
+                .expect("slot is retired");
+            self.free_list.remove(position);
+        }
+
+        self.data[slot].entry.get_or_insert_with(make)
+    }
+
+    /// Returns a mutable reference to the element at the specified index,
+    /// inserting the value produced by `f` into its slot if the slot is empty.
+    ///
+    /// A filled slot keeps its current generation, so an empty slot can only be
+    /// filled through an index whose generation still matches the slot's, e.g.
+    /// after [`take_without_bump`](Self::take_without_bump).
+    ///
+    /// ## Panics
+    /// Panics if the index is out of bounds, if the slot's generation differs from
+    /// the index's (e.g. because the element was removed regularly), or if the
+    /// slot has been retired due to a generation overflow.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push(1);
+    /// *gv.get_or_insert_with(&a, || 0) += 1;
+    /// assert_eq!(gv.get(&a), Some(&2));
+    ///
+    /// gv.take_without_bump(&a);
+    /// *gv.get_or_insert_with(&a, || 0) += 1;
+    /// assert_eq!(gv.get(&a), Some(&1));
+    /// ```
+    pub fn get_or_insert_with<F: FnOnce() -> TEntry>(
+        &mut self,
+        index: &GenerationalIndex<TGeneration>,
+        f: F,
+    ) -> &mut TEntry {
+        let entry = self.data.get(index.index).expect("index out of bounds");
+        assert!(
+            entry.generation == index.generation,
+            "slot is occupied by a different generation"
+        );
+
+        self.get_mut_or_insert_at(index.index, f)
+    }
+
+    /// Retains only the elements for which the predicate returns `true`, then
+    /// removes the trailing empty slots and releases the unused capacity, e.g.
+    /// to clean up at the end of a level.
+    ///
+    /// Empty slots that are followed by occupied ones are kept, so the indices
+    /// of the retained elements stay valid.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv: GenerationalVector<_> = vec![1, 2, 3, 4].into();
+    /// gv.retain_then_shrink(|&value| value < 3);
+    ///
+    /// assert_eq!(gv.num_slots(), 2);
+    /// assert_eq!(gv.capacity(), 2);
+    /// ```
+    pub fn retain_then_shrink(&mut self, f: impl FnMut(&TEntry) -> bool)
+    where
+        TGeneration: PartialOrd,
+    {
+        self.retain(f);
+        self.shrink_to_fit();
+    }
+
+    /// Iterates the live elements whose slot generation is at least `min`,
+    /// e.g. to process only the elements placed into recycled slots.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::<_, u32>::new();
+    /// let a = gv.push("a");
+    /// let _b = gv.push("b");
+    /// gv.remove(&a);
+    /// let c = gv.push("c");
+    ///
+    /// let recycled: Vec<_> = gv.iter_at_least_generation(2).collect();
+    /// assert_eq!(recycled, vec![(c, &"c")]);
+    /// ```
+    pub fn iter_at_least_generation(
+        &self,
+        min: TGeneration,
+    ) -> impl Iterator<Item = (GenerationalIndex<TGeneration>, &TEntry)>
+    where
+        TGeneration: PartialOrd,
+    {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(move |(_, entry)| entry.generation >= min)
+            .filter_map(|(slot, entry)| {
+                entry
+                    .entry
+                    .as_ref()
+                    .map(|value| (GenerationalIndex::new(slot, entry.generation), value))
+            })
+    }
+
+    /// Clones the elements of the specified indices into a new vector, e.g. to
+    /// copy a selection of entities to a scratch arena.
+    ///
+    /// ## Returns
+    /// The new vector and, for each of the specified indices, the index of the
+    /// clone in the new vector, or `None` if the index was invalid.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let _a = gv.push("a");
+    /// let b = gv.push("b");
+    ///
+    /// let (subset, mapping) = gv.clone_subset(&[b]);
+    /// assert_eq!(subset.len(), 1);
+    /// assert_eq!(subset.get(&mapping[0].unwrap()), Some(&"b"));
+    /// ```
+    pub fn clone_subset(
+        &self,
+        handles: &[GenerationalIndex<TGeneration>],
+    ) -> (
+        GenerationalVector<TEntry, TGeneration>,
+        Vec<Option<GenerationalIndex<TGeneration>>>,
+    )
+    where
+        TEntry: Clone,
+    {
+        let mut subset = Self::with_capacity(handles.len());
+        let mapping = handles
+            .iter()
+            .map(|index| self.get(index).map(|value| subset.push(value.clone())))
+            .collect();
+
+        (subset, mapping)
+    }
+
+    /// Produces a deterministic textual dump of the internal layout, e.g. to
+    /// attach to bug reports. Every slot is listed with its generation and
+    /// either its value, `free`, or `retired`, followed by a summary line.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::<_, u32>::with_capacity(2);
+    /// let a = gv.push("a");
+    /// let _b = gv.push("b");
+    /// gv.remove(&a);
+    ///
+    /// assert_eq!(
+    ///     gv.debug_dump(),
+    ///     "slot 0: generation 2, free\n\
+    ///      slot 1: generation 1, occupied: \"b\"\n\
+    ///      len: 1, free: 1, capacity: 2\n"
+    /// );
+    /// ```
+    pub fn debug_dump(&self) -> String
+    where
+        TEntry: Debug,
+        TGeneration: Debug,
+    {
+        let mut dump = String::new();
+        for (slot, entry) in self.data.iter().enumerate() {
+            let _ = write!(dump, "slot {}: generation {:?}, ", slot, entry.generation);
+            let _ = match &entry.entry {
+                Some(value) => writeln!(dump, "occupied: {:?}", value),
+                None if self.retired.contains(&slot) => writeln!(dump, "retired"),
+                None => writeln!(dump, "free"),
+            };
+        }
+
+        let _ = writeln!(
+            dump,
+            "len: {}, free: {}, capacity: {}",
+            self.len(),
+            self.count_num_free(),
+            self.capacity()
+        );
+        dump
+    }
+
+    /// Removes the live element in the lowest slot and returns it along with
+    /// its index, e.g. for FIFO-like eviction in slot order.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let _b = gv.push("b");
+    ///
+    /// assert_eq!(gv.remove_lowest(), Some((a, "a")));
+    /// assert_eq!(gv.len(), 1);
+    /// ```
+    pub fn remove_lowest(&mut self) -> Option<(GenerationalIndex<TGeneration>, TEntry)> {
+        let slot = self.data.iter().position(|entry| entry.entry.is_some())?;
+        self.remove_slot_indexed(slot)
+    }
+
+    /// Removes the live element in the highest slot and returns it along with
+    /// its index, e.g. for LIFO-like eviction in slot order.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let _a = gv.push("a");
+    /// let b = gv.push("b");
+    ///
+    /// assert_eq!(gv.remove_highest(), Some((b, "b")));
+    /// assert_eq!(gv.len(), 1);
+    /// ```
+    pub fn remove_highest(&mut self) -> Option<(GenerationalIndex<TGeneration>, TEntry)> {
+        let slot = self.data.iter().rposition(|entry| entry.entry.is_some())?;
+        self.remove_slot_indexed(slot)
+    }
+
+    /// Removes the element in the specified occupied slot and returns it along with its index.
+    fn remove_slot_indexed(
+        &mut self,
+        slot: usize,
+    ) -> Option<(GenerationalIndex<TGeneration>, TEntry)> {
+        let index = GenerationalIndex::new(slot, self.data[slot].generation);
+        self.release_slot(slot).map(|value| (index, value))
+    }
+
+    /// Clones the live elements of another vector into this one, leaving the
+    /// other vector unchanged. Like [`push`](Self::push), empty slots are
+    /// reused before the vector grows.
+    ///
+    /// ## Returns
+    /// The indices of the inserted clones, in the slot order of `other`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let _a = gv.push("a");
+    ///
+    /// let other: GenerationalVector<_> = vec!["b", "c"].into();
+    /// let indices = gv.extend_from(&other);
+    ///
+    /// assert_eq!(gv.len(), 3);
+    /// assert_eq!(gv.get(&indices[1]), Some(&"c"));
+    /// ```
+    pub fn extend_from(
+        &mut self,
+        other: &GenerationalVector<TEntry, TGeneration>,
+    ) -> Vec<GenerationalIndex<TGeneration>>
+    where
+        TEntry: Clone,
+    {
+        self.data
+            .reserve(other.len().saturating_sub(self.free_list.len()));
+        other.iter().map(|value| self.push(value.clone())).collect()
+    }
+
+    /// Appends `count` elements produced by `make` to the end of the vector,
+    /// claiming a contiguous block of raw slots. Unlike [`push`](Self::push),
+    /// free slots are not reused.
+    ///
+    /// ## Returns
+    /// The range of raw slot indices that were claimed, and the indices of the
+    /// inserted elements in ascending slot order.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let _a = gv.push(0);
+    ///
+    /// let (range, indices) = gv.reserve_id_block(3, || 1);
+    /// assert_eq!(range, 1..4);
+    /// assert_eq!(indices.len(), 3);
+    /// ```
+    pub fn reserve_id_block(
+        &mut self,
+        count: usize,
+        mut make: impl FnMut() -> TEntry,
+    ) -> (Range<usize>, Vec<GenerationalIndex<TGeneration>>) {
+        let range = self.data.len()..self.data.len() + count;
+        self.data.reserve(count);
+        let indices = range.clone().map(|_| self.insert_tail(make())).collect();
+        (range, indices)
+    }
+
+    /// Finds the first run of `len` consecutive free slots, e.g. to place a
+    /// sub-array. Retired slots do not count as free.
+    ///
+    /// ## Returns
+    /// The raw index of the first slot of the run, or `None` if no run fits.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let handles: Vec<_> = (0..5).map(|value| gv.push(value)).collect();
+    /// gv.remove(&handles[1]);
+    /// gv.remove(&handles[2]);
+    ///
+    /// assert_eq!(gv.find_free_run(2), Some(1));
+    /// assert_eq!(gv.find_free_run(3), None);
+    /// ```
+    pub fn find_free_run(&self, len: usize) -> Option<usize> {
+        if len == 0 {
+            return Some(0);
+        }
+
+        let mut start = 0;
+        for (slot, entry) in self.data.iter().enumerate() {
+            if entry.entry.is_some() || self.retired.contains(&slot) {
+                start = slot + 1;
+            } else if slot + 1 - start == len {
+                return Some(start);
+            }
+        }
+
+        None
+    }
+
+    /// Swaps the live values, in ascending slot order, with the elements of
+    /// `values`, e.g. for double-buffering. Generations and occupancy are left
+    /// untouched, so all indices stay valid. Only as many values as the shorter
+    /// of the two holds are swapped.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push(1);
+    /// let b = gv.push(2);
+    ///
+    /// let mut buffer = [10, 20];
+    /// gv.swap_values_with(&mut buffer);
+    ///
+    /// assert_eq!(gv.get(&a), Some(&10));
+    /// assert_eq!(gv.get(&b), Some(&20));
+    /// assert_eq!(buffer, [1, 2]);
+    /// ```
+    pub fn swap_values_with(&mut self, values: &mut [TEntry]) {
+        for (value, other) in self.iter_mut().zip(values.iter_mut()) {
+            core::mem::swap(value, other);
+        }
+    }
+
+    /// Returns the number of slots whose generation has reached
+    /// [`GenerationType::max_generation`]. A nonzero count indicates that
+    /// removing elements from these slots will trigger the [`OverflowPolicy`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::<_, u8>::new();
+    /// let _a = gv.push("a");
+    /// assert_eq!(gv.slots_at_max_generation(), 0);
+    /// ```
+    pub fn slots_at_max_generation(&self) -> usize {
+        let max = TGeneration::max_generation();
+        self.data
+            .iter()
+            .filter(|entry| entry.generation == max)
+            .count()
+    }
+
+    /// Consumes the vector and distributes its live values into two new
+    /// vectors: those for which the predicate returns `true`, and the rest.
+    ///
+    /// Indices are not preserved; the values receive fresh indices in the
+    /// resulting vectors.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let gv: GenerationalVector<_> = vec![1, 2, 3, 4].into();
+    /// let (even, odd) = gv.partition(|&value| value % 2 == 0);
+    ///
+    /// assert_eq!(even.iter().collect::<Vec<_>>(), vec![&2, &4]);
+    /// assert_eq!(odd.iter().collect::<Vec<_>>(), vec![&1, &3]);
+    /// ```
+    pub fn partition(self, mut pred: impl FnMut(&TEntry) -> bool) -> (Self, Self) {
+        let mut matching = Self::new();
+        let mut rest = Self::new();
+        for value in self {
+            if pred(&value) {
+                matching.push(value);
+            } else {
+                rest.push(value);
+            }
+        }
+
+        (matching, rest)
+    }
+
+    /// Determines whether the vector has no empty slots, i.e. whether the
+    /// values occupy the raw slots `0..len()` without holes.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv: GenerationalVector<_> = vec![1, 2].into();
+    /// assert!(gv.is_contiguous());
+    ///
+    /// gv.remove_lowest();
+    /// assert!(!gv.is_contiguous());
+    /// ```
+    pub fn is_contiguous(&self) -> bool {
+        self.len() == self.data.len()
+    }
+
+    /// Returns the indices of all live elements sorted by ascending generation,
+    /// with ties broken by slot. Lower generations indicate less recycled slots,
+    /// e.g. for evicting the least or most recycled elements first.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let b = gv.push("b");
+    /// gv.remove(&a);
+    /// let c = gv.push("c");
+    ///
+    /// assert_eq!(gv.handles_by_generation(), vec![b, c]);
+    /// ```
+    pub fn handles_by_generation(&self) -> Vec<GenerationalIndex<TGeneration>>
+    where
+        TGeneration: Ord,
+    {
+        // The sort is stable, so indices of equal generations remain in slot order.
+        let mut handles: Vec<_> = self.keys().collect();
+        handles.sort_by_key(|index| index.generation);
+        handles
+    }
+
+    /// Determines how many more times the generation of an index's slot can be
+    /// advanced before reaching [`GenerationType::max_generation`], i.e. how many
+    /// more removals the slot tolerates before the [`OverflowPolicy`] applies.
+    /// Callers can use this to move values off slots nearing the limit.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::<_, u8>::new();
+    /// let a = gv.push("a");
+    /// assert_eq!(gv.generation_headroom(&a), Some(254));
+    ///
+    /// gv.remove(&a);
+    /// assert_eq!(gv.generation_headroom(&a), Some(253));
+    /// ```
+    ///
+    /// ## Returns
+    /// `None` if the slot does not exist; `Some` headroom of the slot's current
+    /// generation otherwise.
+    pub fn generation_headroom(&self, index: &GenerationalIndex<TGeneration>) -> Option<u128>
+    where
+        TGeneration: ToPrimitive,
+    {
+        let entry = self.data.get(index.index)?;
+        Some(TGeneration::max_generation().as_u128() - entry.generation.as_u128())
+    }
+
+    /// Inserts all values without growing the backing storage, e.g. for
+    /// real-time code that must not allocate mid-frame. The values are only
+    /// inserted if the free slots and the spare capacity can hold all of them.
+    ///
+    /// ## Returns
+    /// The indices of the inserted values in order, or `Err` containing the
+    /// untouched values if they do not fit.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::with_capacity(2);
+    /// assert!(gv.push_batch_checked(vec!["a", "b"]).is_ok());
+    /// assert_eq!(gv.push_batch_checked(vec!["c"]), Err(vec!["c"]));
+    /// ```
+    pub fn push_batch_checked(
+        &mut self,
+        values: Vec<TEntry>,
+    ) -> Result<Vec<GenerationalIndex<TGeneration>>, Vec<TEntry>> {
+        let room = self.free_list.len() + (self.data.capacity() - self.data.len());
+        if values.len() > room {
+            return Err(values);
+        }
+
+        Ok(values.into_iter().map(|value| self.push(value)).collect())
+    }
+
+    /// Removes every live element that is equal to the preceding live element
+    /// in slot order, analogous to [`Vec::dedup`]. The generations of removed
+    /// elements are advanced.
+    ///
+    /// ## Returns
+    /// The number of removed elements.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv: GenerationalVector<_> = vec![1, 1, 2, 3, 3, 3].into();
+    /// assert_eq!(gv.dedup(), 3);
+    /// assert_eq!(gv.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    pub fn dedup(&mut self) -> usize
+    where
+        TEntry: PartialEq,
+    {
+        let mut removed = 0;
+        let mut previous: Option<usize> = None;
+        for slot in 0..self.data.len() {
+            let current = match &self.data[slot].entry {
+                None => continue,
+                Some(value) => value,
+            };
+
+            match previous {
+                Some(previous) if self.data[previous].entry.as_ref() == Some(current) => {
+                    self.release_slot(slot);
+                    removed += 1;
+                }
+                _ => previous = Some(slot),
+            }
+        }
+
+        removed
+    }
+
+    /// Collects the indices of all live elements into a set, e.g. to build the
+    /// set passed to [`retain_handles_returning_removed`](Self::retain_handles_returning_removed)
+    /// or to compare against a previous snapshot.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    ///
+    /// let set = gv.live_handle_set();
+    /// assert_eq!(set.len(), 1);
+    /// assert!(set.contains(&a));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn live_handle_set(&self) -> HashSet<GenerationalIndex<TGeneration>>
+    where
+        TGeneration: Hash + Eq,
+    {
+        self.keys().collect()
+    }
+
+    /// Moves all live elements in raw slots at or above `at` into a new vector.
+    /// The moved elements are removed from this vector, advancing the
+    /// generations of their slots.
+    ///
+    /// The moved elements receive new indices in the returned vector; their
+    /// indices in this vector become invalid.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv: GenerationalVector<_> = vec!["a", "b", "c"].into();
+    /// let tail = gv.split_off_slots(1);
+    ///
+    /// assert_eq!(gv.iter().collect::<Vec<_>>(), vec![&"a"]);
+    /// assert_eq!(tail.iter().collect::<Vec<_>>(), vec![&"b", &"c"]);
+    /// ```
+    pub fn split_off_slots(&mut self, at: usize) -> GenerationalVector<TEntry, TGeneration> {
+        let mut tail = Self::new();
+        for slot in at..self.data.len() {
+            if self.data[slot].entry.is_none() {
+                continue;
+            }
+
+            if let Some(value) = self.release_slot(slot) {
+                tail.push(value);
+            }
+        }
+
+        tail
+    }
+
+    /// Removes all elements, advancing the generations of their slots so that
+    /// every previously issued index becomes stale, and then inserts the
+    /// specified values, reusing the existing slots and capacity first,
+    /// e.g. to recycle an object pool between phases.
+    ///
+    /// ## Returns
+    /// The indices of the inserted values in order.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    ///
+    /// let indices = gv.reinitialize_from(["b"]);
+    /// assert_eq!(gv.get(&a), None);
+    /// assert_eq!(gv.get(&indices[0]), Some(&"b"));
+    /// assert_eq!(gv.num_slots(), 1);
+    /// ```
+    pub fn reinitialize_from(
+        &mut self,
+        values: impl IntoIterator<Item = TEntry>,
+    ) -> Vec<GenerationalIndex<TGeneration>> {
+        self.clear();
+        values.into_iter().map(|value| self.push(value)).collect()
+    }
+
+    /// Swaps the values of two elements in place. Generations are left
+    /// untouched, so both indices remain valid and resolve to the swapped values.
+    ///
+    /// ## Returns
+    /// `false` if either index is invalid; `true` otherwise.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    /// let b = gv.push("b");
+    ///
+    /// assert!(gv.swap(&a, &b));
+    /// assert_eq!(gv.get(&a), Some(&"b"));
+    /// assert_eq!(gv.get(&b), Some(&"a"));
+    /// ```
+    pub fn swap(
+        &mut self,
+        a: &GenerationalIndex<TGeneration>,
+        b: &GenerationalIndex<TGeneration>,
+    ) -> bool {
+        if a.index == b.index {
+            return self.get(a).is_some() && self.get(b).is_some();
+        }
+
+        match self.get_disjoint_mut(a, b) {
+            Some((x, y)) => {
+                core::mem::swap(x, y);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<TEntry, TGeneration> GenerationalVector<TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    /// Returns a parallel iterator over references to the elements, skipping empty slots.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    /// use rayon::prelude::*;
+    ///
+    /// let gv: GenerationalVector<_> = vec![1, 2, 3].into();
+    /// assert_eq!(gv.par_iter().sum::<i32>(), 6);
+    /// ```
+    pub fn par_iter(&self) -> impl ParallelIterator<Item = &TEntry> + '_
+    where
+        TEntry: Sync,
+        TGeneration: Sync,
+    {
+        self.data
+            .par_iter()
+            .filter_map(|entry| entry.entry.as_ref())
+    }
+
+    /// Returns a parallel iterator over mutable references to the elements,
+    /// skipping empty slots.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut gv: GenerationalVector<_> = vec![1, 2, 3].into();
+    /// gv.par_iter_mut().for_each(|value| *value *= 2);
+    /// assert_eq!(gv.iter().sum::<i32>(), 12);
+    /// ```
+    pub fn par_iter_mut(&mut self) -> impl ParallelIterator<Item = &mut TEntry> + '_
+    where
+        TEntry: Send,
+        TGeneration: Send,
+    {
+        self.data
+            .par_iter_mut()
+            .filter_map(|entry| entry.entry.as_mut())
+    }
 }
 
 impl<TEntry> Default for GenerationalVector<TEntry, DefaultGenerationType> {
@@ -392,9 +3415,70 @@ impl<TEntry> Default for GenerationalVector<TEntry, DefaultGenerationType> {
 
 impl<TGeneration> GenerationalIndex<TGeneration> {
     #[inline(always)]
-    const fn new(index: usize, generation: TGeneration) -> Self {
+    pub(crate) const fn new(index: usize, generation: TGeneration) -> Self {
         Self { index, generation }
     }
+
+    /// Reconstructs an index from its raw slot and generation, e.g. after
+    /// reading it from a custom wire format.
+    ///
+    /// An index constructed this way is only meaningful for the vector it
+    /// was originally obtained from.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    /// use generational_vector::vector::GenerationalIndex;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let a = gv.push("a");
+    ///
+    /// let restored = GenerationalIndex::from_parts(a.index(), a.generation());
+    /// assert_eq!(restored, a);
+    /// assert_eq!(gv.get(&restored), Some(&"a"));
+    /// ```
+    #[inline(always)]
+    pub const fn from_parts(index: usize, generation: TGeneration) -> Self {
+        Self::new(index, generation)
+    }
+
+    /// Returns the raw slot this index points to.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::default();
+    /// let _a = gv.push("a");
+    /// let b = gv.push("b");
+    /// assert_eq!(b.index(), 1);
+    /// ```
+    #[inline(always)]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the generation of this index.
+    ///
+    /// ## Examples
+    /// ```
+    /// use generational_vector::vector::GenerationalVector;
+    ///
+    /// let mut gv = GenerationalVector::<_, u32>::new();
+    /// let a = gv.push("a");
+    /// gv.remove(&a);
+    /// let b = gv.push("b");
+    ///
+    /// assert_eq!(a.generation(), 1);
+    /// assert_eq!(b.generation(), 2);
+    /// ```
+    #[inline(always)]
+    pub fn generation(&self) -> TGeneration
+    where
+        TGeneration: Copy,
+    {
+        self.generation
+    }
 }
 
 impl DeletionResult {
@@ -421,6 +3505,14 @@ where
         }
     }
 
+    #[inline(always)]
+    const fn new_empty(generation: TGeneration) -> Self {
+        Self {
+            entry: None,
+            generation,
+        }
+    }
+
     /// Replaces the content of an empty slot with a new value.
     ///
     /// ## Panics
@@ -441,6 +3533,71 @@ where
     }
 }
 
+/// Retrieves the element at the specified index.
+///
+/// ## Panics
+/// Panics if the index points to an empty or stale slot.
+///
+/// # Examples
+///
+/// ```
+/// use generational_vector::GenerationalVector;
+///
+/// let mut v = GenerationalVector::default();
+/// let a = v.push("a");
+/// assert_eq!(v[&a], "a");
+/// ```
+impl<TEntry, TGeneration> Index<&GenerationalIndex<TGeneration>>
+    for GenerationalVector<TEntry, TGeneration>
+where
+    TGeneration: GenerationType + Debug,
+{
+    type Output = TEntry;
+
+    #[track_caller]
+    fn index(&self, index: &GenerationalIndex<TGeneration>) -> &Self::Output {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "index points to an empty or stale slot: slot {} with generation {:?}",
+                index.index, index.generation
+            ),
+        }
+    }
+}
+
+/// Retrieves a mutable reference to the element at the specified index.
+///
+/// ## Panics
+/// Panics if the index points to an empty or stale slot.
+///
+/// # Examples
+///
+/// ```
+/// use generational_vector::GenerationalVector;
+///
+/// let mut v = GenerationalVector::default();
+/// let a = v.push("a");
+/// v[&a] = "b";
+/// assert_eq!(v[&a], "b");
+/// ```
+impl<TEntry, TGeneration> IndexMut<&GenerationalIndex<TGeneration>>
+    for GenerationalVector<TEntry, TGeneration>
+where
+    TGeneration: GenerationType + Debug,
+{
+    #[track_caller]
+    fn index_mut(&mut self, index: &GenerationalIndex<TGeneration>) -> &mut Self::Output {
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!(
+                "index points to an empty or stale slot: slot {} with generation {:?}",
+                index.index, index.generation
+            ),
+        }
+    }
+}
+
 impl<TEntry, TGeneration> From<Vec<TEntry>> for GenerationalVector<TEntry, TGeneration>
 where
     TGeneration: GenerationType,
@@ -451,6 +3608,89 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<TEntry, TGeneration> TryFrom<SerializedGenerationalVector<TEntry, TGeneration>>
+    for GenerationalVector<TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    type Error = &'static str;
+
+    /// Validates that the free list and the retired slots refer to distinct,
+    /// empty slots and that every empty slot is accounted for by either.
+    fn try_from(
+        value: SerializedGenerationalVector<TEntry, TGeneration>,
+    ) -> Result<Self, Self::Error> {
+        let mut listed = alloc::vec![false; value.data.len()];
+        for &index in value.free_list.iter().chain(value.retired.iter()) {
+            match (value.data.get(index), listed.get_mut(index)) {
+                (Some(entry), Some(seen)) => {
+                    if entry.entry.is_some() {
+                        return Err("free or retired index points to an occupied slot");
+                    }
+                    if *seen {
+                        return Err("duplicate free or retired index");
+                    }
+                    *seen = true;
+                }
+                _ => return Err("free or retired index is out of range"),
+            }
+        }
+
+        let empty = value.data.iter().filter(|e| e.entry.is_none()).count();
+        if empty != value.free_list.len() + value.retired.len() {
+            return Err("empty slot is neither free nor retired");
+        }
+
+        Ok(Self {
+            data: value.data,
+            free_list: value.free_list,
+            retired: value.retired,
+            max_capacity: value.max_capacity,
+            overflow_policy: value.overflow_policy,
+            growth_policy: value.growth_policy,
+            tail_generation: value.tail_generation,
+        })
+    }
+}
+
+impl<TEntry, TGeneration> FromIterator<TEntry> for GenerationalVector<TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    #[inline(always)]
+    fn from_iter<TIter: IntoIterator<Item = TEntry>>(iter: TIter) -> Self {
+        Self::new_from_iter(iter)
+    }
+}
+
+impl<TEntry, TGeneration> Extend<TEntry> for GenerationalVector<TEntry, TGeneration>
+where
+    TGeneration: GenerationType,
+{
+    fn extend<TIter: IntoIterator<Item = TEntry>>(&mut self, iter: TIter) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.data
+            .reserve(lower.saturating_sub(self.free_list.len()));
+
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, TEntry, TGeneration> Extend<&'a TEntry> for GenerationalVector<TEntry, TGeneration>
+where
+    TEntry: Copy + 'a,
+    TGeneration: GenerationType,
+{
+    #[inline(always)]
+    fn extend<TIter: IntoIterator<Item = &'a TEntry>>(&mut self, iter: TIter) {
+        self.extend(iter.into_iter().copied())
+    }
+}
+
 impl<TEntry, TGeneration> IntoIterator for GenerationalVector<TEntry, TGeneration>
 where
     TGeneration: GenerationType,
@@ -459,7 +3699,11 @@ where
     type IntoIter = EntryIntoIterator<TEntry, TGeneration>;
 
     fn into_iter(self) -> Self::IntoIter {
-        EntryIntoIterator { vec: self.data }
+        EntryIntoIterator {
+            current: 0,
+            remaining: self.len(),
+            vec: self.data.into_iter(),
+        }
     }
 }
 
@@ -473,6 +3717,8 @@ where
     fn into_iter(self) -> Self::IntoIter {
         EntryIterator {
             current: 0,
+            end: self.data.len(),
+            remaining: self.len(),
             vec: &self.data,
         }
     }
@@ -488,6 +3734,8 @@ where
     fn into_iter(self) -> Self::IntoIter {
         EntryMutIterator {
             current: 0,
+            end: self.data.len(),
+            remaining: self.len(),
             vec: &mut self.data,
         }
     }
@@ -496,7 +3744,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use std::num::{NonZeroU8, NonZeroUsize};
+    use core::num::{NonZeroU8, NonZeroUsize};
 
     #[test]
     fn insert_after_delete_generation_changes() {
@@ -587,17 +3835,17 @@ mod test {
 
     #[test]
     fn sizeof() {
-        assert_eq!(std::mem::size_of::<GenerationalEntry<u8, usize>>(), 16);
-        assert_eq!(std::mem::size_of::<GenerationalEntry<u8, u32>>(), 8);
-        assert_eq!(std::mem::size_of::<GenerationalEntry<u8, u16>>(), 4);
-        assert_eq!(std::mem::size_of::<GenerationalEntry<u8, u8>>(), 3);
+        assert_eq!(core::mem::size_of::<GenerationalEntry<u8, usize>>(), 16);
+        assert_eq!(core::mem::size_of::<GenerationalEntry<u8, u32>>(), 8);
+        assert_eq!(core::mem::size_of::<GenerationalEntry<u8, u16>>(), 4);
+        assert_eq!(core::mem::size_of::<GenerationalEntry<u8, u8>>(), 3);
 
         assert_eq!(
-            std::mem::size_of::<GenerationalEntry<NonZeroU8, NonZeroUsize>>(),
+            core::mem::size_of::<GenerationalEntry<NonZeroU8, NonZeroUsize>>(),
             16
         );
         assert_eq!(
-            std::mem::size_of::<GenerationalEntry<NonZeroU8, NonZeroU8>>(),
+            core::mem::size_of::<GenerationalEntry<NonZeroU8, NonZeroU8>>(),
             2
         );
     }
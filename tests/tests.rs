@@ -1,4 +1,11 @@
-use generational_vector::GenerationalVector;
+use generational_vector::{
+    vector, DefaultGenerationType, DeletionResult, GenerationAsU128, GenerationOverflow,
+    GenerationType, GenerationalVector, GrowthPolicy, LookupError, OverflowPolicy, RetainReport,
+    WrappingGenerationType,
+};
+use num_traits::One;
+use std::cmp::Ordering;
+use std::collections::{HashMap, TryReserveError};
 
 #[test]
 fn default() {
@@ -54,6 +61,20 @@ fn into_iter() {
     assert!(vec.contains(&"c"));
 }
 
+#[test]
+fn into_iter_matches_iter_order() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..8).map(|value| gv.push(value)).collect();
+    gv.remove(handles[1]);
+    gv.remove(handles[6]);
+    gv.push(10);
+
+    let borrowed: Vec<_> = gv.iter().cloned().collect();
+    let owned: Vec<_> = gv.into_iter().collect();
+    assert_eq!(owned, borrowed);
+    assert_eq!(owned, vec![0, 2, 3, 4, 5, 10, 7]);
+}
+
 #[test]
 fn iter() {
     let gv: GenerationalVector<_> = vec![10, 20, 30, 40, 50].into();
@@ -263,3 +284,2582 @@ fn delete_all_and_insert() {
 
     assert_eq!(gv.count_num_free(), 1);
 }
+
+#[test]
+fn prune_trailing_free_reporting() {
+    let mut gv = GenerationalVector::default();
+
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let c = gv.push("c");
+    let d = gv.push("d");
+    let e = gv.push("e");
+
+    gv.remove(b);
+    gv.remove(e);
+    gv.remove(d);
+    assert_eq!(gv.num_slots(), 5);
+
+    // Only the trailing holes are reclaimed; slot 1 stays a hole.
+    assert_eq!(gv.prune_trailing_free_reporting(), vec![3, 4]);
+    assert_eq!(gv.num_slots(), 3);
+    assert_eq!(gv.count_num_free(), 1);
+    assert_eq!(gv.get(a), Some(&"a"));
+    assert_eq!(gv.get(c), Some(&"c"));
+
+    // Nothing left to prune.
+    assert!(gv.prune_trailing_free_reporting().is_empty());
+    assert_eq!(gv.num_slots(), 3);
+}
+
+#[test]
+fn get_or_push_hit() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+
+    let (index, created) = gv.get_or_push(Some(&a), || "b");
+    assert!(!created);
+    assert_eq!(index, a);
+    assert_eq!(gv.get(index), Some(&"a"));
+    assert_eq!(gv.len(), 1);
+}
+
+#[test]
+fn get_or_push_miss() {
+    let mut gv = GenerationalVector::default();
+
+    let (a, created) = gv.get_or_push(None, || "a");
+    assert!(created);
+    assert_eq!(gv.get(a), Some(&"a"));
+
+    // A stale index is a miss as well.
+    gv.remove(a);
+    let (b, created) = gv.get_or_push(Some(&a), || "b");
+    assert!(created);
+    assert_ne!(a, b);
+    assert_eq!(gv.get(b), Some(&"b"));
+    assert_eq!(gv.len(), 1);
+}
+
+#[test]
+fn slot_values() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let _ = gv.push("c");
+    gv.remove(a);
+    gv.remove(b);
+    let _ = gv.push("d");
+
+    // Slot 1 was reused for "d", slot 0 is still free.
+    let slots: Vec<_> = gv.slot_values().collect();
+    assert_eq!(slots.len(), gv.num_slots());
+    assert_eq!(slots[0], (0, 2, None));
+    assert_eq!(slots[1], (1, 2, Some(&"d")));
+    assert_eq!(slots[2], (2, 1, Some(&"c")));
+}
+
+#[test]
+fn count_occupied() {
+    let mut gv = GenerationalVector::default();
+
+    let handles: Vec<_> = (0..8).map(|value| gv.push(value)).collect();
+    gv.remove(handles[1]);
+    gv.remove(handles[2]);
+    gv.remove(handles[6]);
+
+    // Slots 0, 3, 4, 5 and 7 are occupied.
+    assert_eq!(gv.count_occupied(0..4), 2);
+    assert_eq!(gv.count_occupied(1..=2), 0);
+    assert_eq!(gv.count_occupied(5..), 2);
+    assert_eq!(gv.count_occupied(6..100), 1);
+    assert_eq!(gv.count_occupied(..), gv.len());
+    assert_eq!(gv.count_occupied(0..gv.num_slots()), 5);
+}
+
+#[test]
+fn swap_remove_returning_moved() {
+    let mut gv = GenerationalVector::default();
+
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let c = gv.push("c");
+    let d = gv.push("d");
+
+    // "d" is moved from the last slot into the slot of "b".
+    let moved = gv.swap_remove_returning_moved(&b).unwrap();
+    assert_eq!(gv.get(moved), Some(&"d"));
+    assert_eq!(gv.get(b), None);
+    assert_eq!(gv.get(d), None);
+    assert_eq!(gv.get(a), Some(&"a"));
+    assert_eq!(gv.get(c), Some(&"c"));
+    assert_eq!(gv.len(), 3);
+    assert_eq!(gv.count_num_free(), 1);
+
+    // "c" now occupies the highest slot, so nothing is moved.
+    assert_eq!(gv.swap_remove_returning_moved(&c), None);
+    assert_eq!(gv.get(c), None);
+    assert_eq!(gv.len(), 2);
+
+    // Stale indices are ignored.
+    assert_eq!(gv.swap_remove_returning_moved(&b), None);
+    assert_eq!(gv.len(), 2);
+}
+
+#[test]
+fn reserve_to_len() {
+    let mut gv = GenerationalVector::default();
+
+    let handles: Vec<_> = (0..5).map(|value| gv.push(value)).collect();
+    gv.remove(handles[1]);
+    gv.remove(handles[3]);
+
+    gv.reserve_to_len(100);
+    let capacity = gv.capacity();
+    assert!(capacity >= 100);
+
+    while gv.len() < 100 {
+        gv.push(42);
+    }
+
+    assert_eq!(gv.capacity(), capacity);
+}
+
+#[test]
+fn reserve_to_len_with_retired_slot() {
+    let mut gv = vector::GenerationalVector::<_, u8>::new();
+    gv.set_overflow_policy(OverflowPolicy::Retire);
+    let index = exhaust_first_slot(&mut gv);
+    gv.remove(index);
+    gv.shrink_to_fit();
+    assert_eq!(gv.num_slots(), 1);
+
+    gv.reserve_to_len(100);
+    let capacity = gv.capacity();
+    assert!(capacity >= 101);
+
+    while gv.len() < 100 {
+        gv.push("a");
+    }
+
+    assert_eq!(gv.capacity(), capacity);
+}
+
+#[test]
+fn iter_live_full() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let _ = gv.push("c");
+    gv.remove(a);
+    gv.remove(b);
+    let _ = gv.push("d");
+
+    let live: Vec<_> = gv.iter_live_full().collect();
+    assert_eq!(live, vec![(1, 2, &"d"), (2, 1, &"c")]);
+
+    for (slot, generation, _) in live {
+        assert_eq!(gv.generation_at(slot), Some(generation));
+    }
+}
+
+#[test]
+fn remove_returning_next_live() {
+    let mut gv = GenerationalVector::default();
+
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let c = gv.push("c");
+    let d = gv.push("d");
+    gv.remove(c);
+
+    // Slot 2 is a hole, so the neighbor of "b" is "d".
+    let (result, next) = gv.remove_returning_next_live(&b);
+    assert_eq!(result, DeletionResult::Ok);
+    assert_eq!(next, Some(d));
+    assert_eq!(gv.get(next.unwrap()), Some(&"d"));
+
+    // The last live element has no successor.
+    assert_eq!(
+        gv.remove_returning_next_live(&d),
+        (DeletionResult::Ok, None)
+    );
+
+    // Failed deletions do not report a neighbor.
+    assert_eq!(
+        gv.remove_returning_next_live(&b),
+        (DeletionResult::NotFound, None)
+    );
+    assert_eq!(gv.get(a), Some(&"a"));
+}
+
+#[test]
+fn with_max_and_capacity() {
+    let mut gv = GenerationalVector::with_max_and_capacity(4, 4);
+    assert_eq!(gv.capacity(), 4);
+
+    let handles: Vec<_> = (0..4).map(|value| gv.try_push(value).unwrap()).collect();
+    assert_eq!(gv.try_push(4), Err(4));
+    assert_eq!(gv.len(), 4);
+    assert_eq!(gv.capacity(), 4);
+
+    // Freed slots can be reused without growing.
+    gv.remove(handles[2]);
+    let index = gv.try_push(5).unwrap();
+    assert_eq!(gv.get(index), Some(&5));
+    assert_eq!(gv.try_push(6), Err(6));
+    assert_eq!(gv.capacity(), 4);
+}
+
+#[test]
+fn get_slots_mut() {
+    let mut gv = GenerationalVector::default();
+
+    let a = gv.push(1);
+    let b = gv.push(2);
+    let c = gv.push(3);
+
+    {
+        let (x, y) = gv.get_slots_mut(2, 0).unwrap();
+        assert_eq!((*x, *y), (3, 1));
+        *x += 10;
+        *y += 20;
+    }
+    assert_eq!(gv.get(a), Some(&21));
+    assert_eq!(gv.get(c), Some(&13));
+
+    // Aliasing, out-of-range and empty slots are rejected.
+    assert!(gv.get_slots_mut(1, 1).is_none());
+    assert!(gv.get_slots_mut(0, 3).is_none());
+    gv.remove(b);
+    assert!(gv.get_slots_mut(0, 1).is_none());
+}
+
+#[test]
+fn owns() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    let _ = gv.push("b");
+    assert!(gv.owns(&a));
+
+    // A handle whose generation exceeds any generation seen here.
+    let mut other = GenerationalVector::default();
+    let x = other.push("x");
+    other.remove(x);
+    let foreign = other.push("y");
+    assert!(!gv.owns(&foreign));
+
+    // Once the slot has been recycled often enough, the handle becomes plausible.
+    gv.remove(a);
+    assert!(gv.owns(&foreign));
+
+    // A handle pointing beyond the last slot.
+    let mut large = GenerationalVector::default();
+    let out_of_range = (0..3).map(|value| large.push(value)).last().unwrap();
+    assert!(!gv.owns(&out_of_range));
+}
+
+#[test]
+fn push_mut() {
+    let mut gv = GenerationalVector::default();
+
+    let a = gv.push(String::from("a"));
+    gv.remove(a);
+
+    // The freed slot is reused and the reference points into it.
+    let (b, value) = gv.push_mut(String::from("b"));
+    value.push_str("cd");
+
+    assert_eq!(gv.get(b).map(String::as_str), Some("bcd"));
+    assert_eq!(gv.get(a), None);
+    assert_eq!(gv.len(), 1);
+}
+
+#[test]
+fn remove_no_bump_keeps_generation() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+
+    let a = gv.push("a");
+    let _ = gv.push("b");
+    assert_eq!(gv.remove_no_bump(&a), DeletionResult::Ok);
+    assert_eq!(gv.remove_no_bump(&a), DeletionResult::NotFound);
+    assert_eq!(gv.generation_at(0), Some(1));
+    assert_eq!(gv.len(), 1);
+
+    // The reused slot hands out the very same index again.
+    let c = gv.push("c");
+    assert_eq!(a, c);
+    assert_eq!(gv.generation_at(0), Some(1));
+
+    // A regular removal still advances the generation.
+    gv.remove(c);
+    let d = gv.push("d");
+    assert_ne!(c, d);
+    assert_eq!(gv.generation_at(0), Some(2));
+}
+
+#[test]
+fn into_iter_with_handles() {
+    let mut gv = GenerationalVector::default();
+
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let c = gv.push("c");
+    let d = gv.push("d");
+    gv.remove(b);
+    gv.remove(a);
+    let e = gv.push("e");
+
+    // The handles are the ones `get` accepts before consumption.
+    let expected: Vec<_> = [e, c, d]
+        .into_iter()
+        .map(|index| (index, *gv.get(index).unwrap()))
+        .collect();
+
+    let pairs: Vec<_> = gv.into_iter().with_handles().collect();
+    assert_eq!(pairs, expected);
+    assert_eq!(pairs[0], (e, "e"));
+}
+
+/// Pushes and removes an element until slot 0 reaches the maximum `u8` generation.
+fn exhaust_first_slot(
+    gv: &mut vector::GenerationalVector<&'static str, u8>,
+) -> vector::GenerationalIndex<u8> {
+    let mut index = gv.push("a");
+    while gv.generation_at(0) != Some(u8::MAX) {
+        gv.remove(index);
+        index = gv.push("a");
+    }
+    index
+}
+
+#[test]
+#[should_panic(expected = "generation counter overflowed")]
+fn overflow_policy_panic() {
+    let mut gv = vector::GenerationalVector::<_, u8>::new();
+    assert_eq!(gv.overflow_policy(), OverflowPolicy::Panic);
+
+    let index = exhaust_first_slot(&mut gv);
+    gv.remove(index);
+}
+
+#[test]
+fn overflow_policy_saturate() {
+    let mut gv = vector::GenerationalVector::<_, u8>::new();
+    gv.set_overflow_policy(OverflowPolicy::Saturate);
+
+    let index = exhaust_first_slot(&mut gv);
+    assert_eq!(gv.remove(index), DeletionResult::Ok);
+    assert_eq!(gv.generation_at(0), Some(u8::MAX));
+
+    // The slot is reused at the maximum generation.
+    let b = gv.push("b");
+    assert_eq!(b, index);
+    assert_eq!(gv.generation_at(0), Some(u8::MAX));
+    assert_eq!(gv.num_slots(), 1);
+}
+
+#[test]
+fn overflow_policy_retire() {
+    let mut gv = vector::GenerationalVector::<_, u8>::new();
+    gv.set_overflow_policy(OverflowPolicy::Retire);
+
+    let index = exhaust_first_slot(&mut gv);
+    assert_eq!(gv.remove(index), DeletionResult::Ok);
+    assert_eq!(gv.get(index), None);
+    assert_eq!(gv.len(), 0);
+    assert_eq!(gv.count_num_free(), 0);
+
+    // The retired slot is skipped and the vector grows instead.
+    let b = gv.push("b");
+    assert_eq!(gv.get(index), None);
+    assert_eq!(gv.get(b), Some(&"b"));
+    assert_eq!(gv.num_slots(), 2);
+    assert_eq!(gv.len(), 1);
+}
+
+#[test]
+fn overflow_policy_wrap() {
+    let mut gv = vector::GenerationalVector::<_, u8>::new();
+    gv.set_overflow_policy(OverflowPolicy::Wrap);
+
+    let index = exhaust_first_slot(&mut gv);
+    assert_eq!(gv.remove(index), DeletionResult::Ok);
+    assert_eq!(gv.generation_at(0), Some(1));
+
+    let b = gv.push("b");
+    assert_ne!(b, index);
+    assert_eq!(gv.get(index), None);
+    assert_eq!(gv.num_slots(), 1);
+}
+
+#[test]
+fn to_sorted_vec_by() {
+    let mut gv = GenerationalVector::default();
+
+    let handles: Vec<_> = [5, 3, 8, 1, 9, 2].map(|value| gv.push(value)).to_vec();
+    gv.remove(handles[2]);
+    gv.remove(handles[3]);
+
+    let sorted = gv.to_sorted_vec_by(|a, b| b.cmp(a));
+    assert_eq!(sorted, vec![9, 5, 3, 2]);
+
+    // The vector itself is left untouched.
+    assert_eq!(gv.len(), 4);
+    assert_eq!(gv.get(handles[0]), Some(&5));
+    assert_eq!(gv.get(handles[4]), Some(&9));
+}
+
+#[test]
+fn fold_indexed() {
+    let mut gv = GenerationalVector::default();
+
+    let handles: Vec<_> = [4, 7, 2, 9, 5].map(|value| gv.push(value)).to_vec();
+    gv.remove(handles[3]);
+
+    let max = gv.fold_indexed(None, |max, index, &value| match max {
+        Some((_, best)) if best >= value => max,
+        _ => Some((index, value)),
+    });
+    assert_eq!(max, Some((handles[1], 7)));
+    assert_eq!(gv.get(max.unwrap().0), Some(&7));
+
+    let sum = gv.fold_indexed(0, |sum, _, value| sum + value);
+    assert_eq!(sum, 18);
+}
+
+#[test]
+fn clear_and_reserve() {
+    let mut gv = GenerationalVector::default();
+
+    let handles: Vec<_> = (0..5).map(|value| gv.push(value)).collect();
+    gv.remove(handles[1]);
+
+    gv.clear_and_reserve(64);
+    assert!(gv.is_empty());
+    assert!(gv.capacity() >= 64);
+    assert_eq!(gv.count_num_free(), 5);
+    for index in &handles {
+        assert_eq!(gv.get(index), None);
+    }
+
+    // Slots are reused with new generations.
+    let index = gv.push(42);
+    assert!(!handles.contains(&index));
+    assert_eq!(gv.num_slots(), 5);
+}
+
+#[test]
+fn generation_delta() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+
+    let a = gv.push("a");
+    let b = gv.push("b");
+    assert_eq!(gv.generation_delta(&a), Some(0));
+
+    gv.remove(a);
+    let c = gv.push("c");
+    gv.remove(c);
+    let d = gv.push("d");
+
+    assert_eq!(gv.generation_delta(&a), Some(2));
+    assert_eq!(gv.generation_delta(&c), Some(1));
+    assert_eq!(gv.generation_delta(&d), Some(0));
+    assert_eq!(gv.generation_delta(&b), Some(0));
+
+    // Indices ahead of the slot or beyond the last slot yield no delta.
+    let mut other = vector::GenerationalVector::<_, u32>::new();
+    let _ = other.push("x");
+    let _ = other.push("y");
+    let out_of_range = other.push("z");
+    assert_eq!(gv.generation_delta(&out_of_range), None);
+    let mut empty = vector::GenerationalVector::<_, u32>::new();
+    let _ = empty.push("x");
+    assert_eq!(empty.generation_delta(&d), None);
+}
+
+#[test]
+fn generation_delta_default_generation_type() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    assert_eq!(gv.generation_delta(&a), Some(0));
+
+    gv.remove(a);
+    assert_eq!(gv.generation_delta(&a), Some(1));
+    assert_eq!(gv.generation_headroom(&a), Some(usize::MAX as u128 - 2));
+}
+
+#[test]
+fn retain_first_n() {
+    let mut gv = GenerationalVector::default();
+
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let c = gv.push("c");
+    let d = gv.push("d");
+    let e = gv.push("e");
+    let f = gv.push("f");
+    gv.remove(b);
+    assert_eq!(gv.len(), 5);
+
+    assert_eq!(gv.retain_first_n(3), 2);
+    assert_eq!(gv.len(), 3);
+    assert_eq!(gv.get(a), Some(&"a"));
+    assert_eq!(gv.get(c), Some(&"c"));
+    assert_eq!(gv.get(d), Some(&"d"));
+    assert_eq!(gv.get(e), None);
+    assert_eq!(gv.get(f), None);
+
+    assert_eq!(gv.retain_first_n(5), 0);
+    assert_eq!(gv.len(), 3);
+}
+
+#[test]
+fn extend_reserving() {
+    let mut gv = GenerationalVector::default();
+
+    let a = gv.push(0);
+    let _ = gv.push(1);
+    let _ = gv.push(2);
+    gv.remove(a);
+    assert_eq!(gv.capacity(), 4);
+
+    // One slot is reused, 99 are appended in a single allocation
+    // instead of repeatedly doubling the capacity.
+    let indices = gv.extend_reserving(100..200);
+    assert_eq!(gv.capacity(), 102);
+    assert_eq!(gv.len(), 102);
+    assert_eq!(gv.count_num_free(), 0);
+
+    assert_eq!(indices.len(), 100);
+    for (value, index) in (100..200).zip(&indices) {
+        assert_eq!(gv.get(index), Some(&value));
+    }
+}
+
+#[test]
+fn get_disjoint_slice_mut() {
+    let mut gv = GenerationalVector::default();
+
+    let handles: Vec<_> = (0..5).map(|value| gv.push(value)).collect();
+    let selection = [handles[4], handles[0], handles[2]];
+
+    let values = gv.get_disjoint_slice_mut(&selection).unwrap();
+    assert_eq!(
+        values.iter().map(|value| **value).collect::<Vec<_>>(),
+        vec![4, 0, 2]
+    );
+    for value in values {
+        *value += 100;
+    }
+
+    assert_eq!(gv.get(handles[4]), Some(&104));
+    assert_eq!(gv.get(handles[0]), Some(&100));
+    assert_eq!(gv.get(handles[2]), Some(&102));
+    assert_eq!(gv.get(handles[1]), Some(&1));
+
+    assert!(gv.get_disjoint_slice_mut(&[]).unwrap().is_empty());
+}
+
+#[test]
+fn get_disjoint_slice_mut_rejects_aliasing_and_stale() {
+    let mut gv = GenerationalVector::default();
+
+    let a = gv.push(1);
+    let b = gv.push(2);
+    let c = gv.push(3);
+
+    assert!(gv.get_disjoint_slice_mut(&[a, b, a]).is_none());
+
+    gv.remove(c);
+    let d = gv.push(4);
+    assert!(gv.get_disjoint_slice_mut(&[a, c]).is_none());
+
+    // Same slot, different generations.
+    assert!(gv.get_disjoint_slice_mut(&[d, c]).is_none());
+    assert!(gv.get_disjoint_slice_mut(&[a, b, d]).is_some());
+}
+
+#[test]
+fn touch() {
+    let mut gv = GenerationalVector::default();
+
+    let a = gv.push("a");
+    let b = gv.push("b");
+
+    let touched = gv.touch(&a).unwrap();
+    assert_ne!(touched, a);
+    assert_eq!(gv.get(a), None);
+    assert_eq!(gv.get(touched), Some(&"a"));
+    assert_eq!(gv.get(b), Some(&"b"));
+    assert_eq!(gv.len(), 2);
+
+    // Stale and removed indices cannot be touched.
+    assert_eq!(gv.touch(&a), None);
+    gv.remove(b);
+    assert_eq!(gv.touch(&b), None);
+}
+
+#[test]
+fn touch_saturated_generation() {
+    let mut gv = vector::GenerationalVector::new();
+    gv.set_overflow_policy(OverflowPolicy::Saturate);
+    let index = exhaust_first_slot(&mut gv);
+
+    // The generation cannot advance, so the index stays valid and nothing is returned.
+    assert_eq!(gv.touch(&index), None);
+    assert_eq!(gv.get(index), Some(&"a"));
+    assert_eq!(gv.generation_at(0), Some(u8::MAX));
+}
+
+#[test]
+fn stats() {
+    let gv: GenerationalVector<i32> = GenerationalVector::new();
+    let stats = gv.stats();
+    assert_eq!(stats.len, 0);
+    assert_eq!(stats.slots, 0);
+    assert_eq!(stats.utilization, 0.0);
+
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..8).map(|value| gv.push(value)).collect();
+    gv.remove(handles[0]);
+    gv.remove(handles[5]);
+
+    let stats = gv.stats();
+    assert_eq!(stats.len, gv.len());
+    assert_eq!(stats.free, gv.count_num_free());
+    assert_eq!(stats.slots, gv.num_slots());
+    assert_eq!(stats.capacity, gv.capacity());
+    assert_eq!(stats.utilization, 0.75);
+}
+
+#[test]
+fn remove_sorted_matches_remove() {
+    let mut batched = GenerationalVector::default();
+    let mut individual = GenerationalVector::default();
+
+    let handles: Vec<_> = (0..10).map(|value| batched.push(value)).collect();
+    for value in 0..10 {
+        individual.push(value);
+    }
+
+    individual.remove(handles[7]);
+    batched.remove(handles[7]);
+
+    let selection = [handles[1], handles[3], handles[3], handles[7], handles[8]];
+    let removed = batched.remove_sorted(&selection);
+    let expected = selection
+        .iter()
+        .filter(|index| individual.remove(*index) == DeletionResult::Ok)
+        .count();
+
+    assert_eq!(removed, 3);
+    assert_eq!(removed, expected);
+    assert_eq!(batched.len(), individual.len());
+    assert_eq!(batched.count_num_free(), individual.count_num_free());
+
+    // Both vectors reuse the freed slots in the same order.
+    for value in 10..14 {
+        assert_eq!(batched.push(value), individual.push(value));
+    }
+}
+
+#[test]
+fn iter_filter_indices() {
+    let mut gv = GenerationalVector::default();
+
+    let handles: Vec<_> = (0..10).map(|value| gv.push(value)).collect();
+    gv.remove(handles[4]);
+
+    let matches: Vec<_> = gv
+        .iter()
+        .filter_indices(|&value| value % 2 == 0)
+        .map(|(index, _)| index)
+        .collect();
+
+    assert_eq!(
+        matches,
+        vec![handles[0], handles[2], handles[6], handles[8]]
+    );
+    for index in matches {
+        assert_eq!(gv.get(index).unwrap() % 2, 0);
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn retain_handles_returning_removed() {
+    let mut gv = GenerationalVector::default();
+
+    let handles: Vec<_> = (0..6).map(|value| gv.push(value * 10)).collect();
+    gv.remove(handles[2]);
+
+    let keep = std::collections::HashSet::from([handles[0], handles[3], handles[5]]);
+    let removed = gv.retain_handles_returning_removed(&keep);
+
+    assert_eq!(removed, vec![(handles[1], 10), (handles[4], 40)]);
+    assert_eq!(gv.len(), 3);
+    for (index, _) in &removed {
+        assert_eq!(gv.get(index), None);
+    }
+    for index in &keep {
+        assert!(gv.get(index).is_some());
+    }
+}
+
+#[test]
+fn grow_with_empty() {
+    let mut gv = GenerationalVector::default();
+
+    let a = gv.push("a");
+    let b = gv.push("b");
+    gv.remove(a);
+
+    let range = gv.grow_with_empty(3);
+    assert_eq!(range, 2..5);
+    assert_eq!(gv.num_slots(), 5);
+    assert_eq!(gv.count_num_free(), 4);
+    assert_eq!(gv.len(), 1);
+
+    // The new slots are filled in ascending order, then the older free slot.
+    let pushed: Vec<_> = ["c", "d", "e", "f"].map(|value| gv.push(value)).to_vec();
+    let slots: Vec<_> = gv
+        .iter_live_full()
+        .map(|(slot, _, value)| (slot, *value))
+        .collect();
+    assert_eq!(
+        slots,
+        vec![(0, "f"), (1, "b"), (2, "c"), (3, "d"), (4, "e")]
+    );
+    assert_eq!(gv.get(pushed[0]), Some(&"c"));
+    assert_eq!(gv.get(b), Some(&"b"));
+    assert_eq!(gv.num_slots(), 5);
+}
+
+#[test]
+fn handle_generation_cmp() {
+    type Vector = vector::GenerationalVector<&'static str, u32>;
+    let mut gv = Vector::new();
+
+    let a = gv.push("a");
+    gv.remove(a);
+    let b = gv.push("b");
+
+    // `b` lives in the second generation of its slot.
+    assert_eq!(Vector::handle_generation_cmp(&b, 3), Ordering::Less);
+    assert_eq!(Vector::handle_generation_cmp(&b, 2), Ordering::Equal);
+    assert_eq!(Vector::handle_generation_cmp(&b, 1), Ordering::Greater);
+    assert_eq!(Vector::handle_generation_cmp(&a, 2), Ordering::Less);
+}
+
+#[test]
+fn iter_mut_indexed() {
+    let mut gv = GenerationalVector::default();
+
+    let handles: Vec<_> = (0..5).map(|value| gv.push(value)).collect();
+    gv.remove(handles[1]);
+
+    let mut seen = Vec::new();
+    for (index, value) in gv.iter_mut().indexed() {
+        *value *= 10;
+        seen.push(index);
+    }
+
+    assert_eq!(seen, vec![handles[0], handles[2], handles[3], handles[4]]);
+    for (index, expected) in seen.iter().zip([0, 20, 30, 40]) {
+        assert_eq!(gv.get(index), Some(&expected));
+    }
+}
+
+#[test]
+fn reclaim_retired() {
+    let mut gv = vector::GenerationalVector::<_, u8>::new();
+    gv.set_overflow_policy(OverflowPolicy::Retire);
+
+    let index = exhaust_first_slot(&mut gv);
+    gv.remove(index);
+    assert_eq!(gv.count_num_free(), 0);
+
+    assert_eq!(gv.reclaim_retired(), 1);
+    assert_eq!(gv.count_num_free(), 1);
+    assert_eq!(gv.generation_at(0), Some(1));
+    assert_eq!(gv.reclaim_retired(), 0);
+
+    // The next push reuses the slot at generation one.
+    let b = gv.push("b");
+    assert_eq!(gv.num_slots(), 1);
+    assert_eq!(gv.generation_at(0), Some(1));
+    assert_eq!(gv.get(b), Some(&"b"));
+    assert_eq!(gv.get(index), None);
+}
+
+#[test]
+fn try_reserve() {
+    let mut gv = GenerationalVector::default();
+
+    let a = gv.push(1u64);
+    let _ = gv.push(2);
+    gv.remove(a);
+
+    let result: Result<(), TryReserveError> = gv.try_reserve(100);
+    assert!(result.is_ok());
+    assert!(gv.capacity() >= 100);
+
+    // Requests exceeding the address space fail gracefully.
+    assert!(gv.try_reserve(usize::MAX).is_err());
+    assert_eq!(gv.len(), 1);
+}
+
+#[test]
+fn expect_returns_value() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    assert_eq!(gv.expect(&a, "a should exist"), &"a");
+}
+
+#[test]
+#[should_panic(expected = "a should exist: no value at slot 0 with generation 1")]
+fn expect_panics_with_handle_details() {
+    let mut gv: vector::GenerationalVector<&str, u32> = vector::GenerationalVector::new();
+    let a = gv.push("a");
+    gv.remove(a);
+    gv.expect(&a, "a should exist");
+}
+
+#[test]
+fn saturating_next_generation() {
+    type Gv = vector::GenerationalVector<(), u8>;
+    assert_eq!(Gv::saturating_next_generation(1), 2);
+    assert_eq!(Gv::saturating_next_generation(127), 128);
+    assert_eq!(Gv::saturating_next_generation(254), 255);
+    assert_eq!(Gv::saturating_next_generation(255), 255);
+}
+
+#[test]
+fn iter_zipped() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let c = gv.push("c");
+    let d = gv.push("d");
+    gv.remove(b);
+
+    // The parallel slice does not cover the last slot.
+    let positions = [10, 20, 30];
+
+    let zipped: Vec<_> = gv.iter_zipped(&positions).collect();
+    assert_eq!(zipped, vec![(a, &"a", &10), (c, &"c", &30)]);
+    assert!(zipped.iter().all(|&(handle, _, _)| handle != d));
+}
+
+#[test]
+fn layout_and_values_round_trip() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let c = gv.push("c");
+    gv.remove(b);
+    gv.remove(a);
+    let a2 = gv.push("A");
+
+    let (layout, values) = gv.into_layout_and_values();
+    assert_eq!(layout, vec![(2, true), (2, false), (1, true)]);
+    assert_eq!(values, vec!["A", "c"]);
+
+    let mut gv = vector::GenerationalVector::from_layout_and_values(layout, values).unwrap();
+    assert_eq!(gv.len(), 2);
+    assert_eq!(gv.get(a), None);
+    assert_eq!(gv.get(a2), Some(&"A"));
+    assert_eq!(gv.get(b), None);
+    assert_eq!(gv.get(c), Some(&"c"));
+
+    // Free slots are reused after reconstruction.
+    let d = gv.push("d");
+    assert_eq!(gv.num_slots(), 3);
+    assert_ne!(d, b);
+
+    // Mismatched inputs are rejected.
+    assert!(
+        vector::GenerationalVector::<&str, u32>::from_layout_and_values(vec![(1, true)], vec![])
+            .is_none()
+    );
+}
+
+#[test]
+fn layout_and_values_round_trip_keeps_trimmed_indices_stale() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+    let a = gv.push("a");
+    let b = gv.push("b");
+    gv.remove(b);
+    gv.shrink_to_fit();
+    assert_eq!(gv.num_slots(), 1);
+
+    let (layout, values) = gv.into_layout_and_values();
+    let mut gv = vector::GenerationalVector::from_layout_and_values(layout, values).unwrap();
+    let c = gv.push("c");
+    assert_eq!(gv.get(a), Some(&"a"));
+    assert_eq!(gv.get(b), None);
+    assert_eq!(gv.get(c), Some(&"c"));
+}
+
+#[test]
+fn retain_mut_indexed() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+    let b = gv.push(2);
+    let c = gv.push(3);
+    let d = gv.push(4);
+
+    // Drop even values and the value at `c`, increment the rest.
+    gv.retain_mut_indexed(|index, value| {
+        *value += 100;
+        index != c && *value % 2 == 1
+    });
+
+    assert_eq!(gv.len(), 1);
+    assert_eq!(gv.get(a), Some(&101));
+    assert_eq!(gv.get(b), None);
+    assert_eq!(gv.get(c), None);
+    assert_eq!(gv.get(d), None);
+
+    // Removed slots are reused with a new generation.
+    let e = gv.push(5);
+    assert_ne!(e, b);
+    assert_ne!(e, c);
+    assert_ne!(e, d);
+}
+
+#[test]
+fn bump_all_generations() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let c = gv.push("c");
+    gv.remove(b);
+
+    gv.bump_all_generations().unwrap();
+
+    assert_eq!(gv.get(a), None);
+    assert_eq!(gv.get(b), None);
+    assert_eq!(gv.get(c), None);
+    assert_eq!(gv.iter().collect::<Vec<_>>(), vec![&"a", &"c"]);
+
+    let keys: Vec<_> = gv.keys().collect();
+    assert_eq!(keys.len(), 2);
+    assert_eq!(gv.get(keys[0]), Some(&"a"));
+    assert_eq!(gv.get(keys[1]), Some(&"c"));
+}
+
+#[test]
+fn bump_all_generations_reports_overflow() {
+    let mut gv = vector::GenerationalVector::new();
+    let last = exhaust_first_slot(&mut gv);
+    let b = gv.push("b");
+
+    assert_eq!(
+        gv.bump_all_generations(),
+        Err(GenerationOverflow { slot: 0 })
+    );
+
+    // Nothing changed.
+    assert_eq!(gv.get(last), Some(&"a"));
+    assert_eq!(gv.get(b), Some(&"b"));
+}
+
+#[test]
+fn with_free_list_capacity() {
+    let mut gv = GenerationalVector::with_free_list_capacity(4, 100);
+    assert!(gv.capacity() >= 4);
+    assert!(gv.free_list_capacity() >= 100);
+
+    // Small requests still provide the inline capacity.
+    let small: GenerationalVector<u8> = GenerationalVector::with_free_list_capacity(0, 1);
+    assert!(small.free_list_capacity() >= vector::FREE_LIST_CAPACITY);
+
+    let idxs: Vec<_> = (0..100).map(|value| gv.push(value)).collect();
+    let capacity = gv.free_list_capacity();
+    for idx in &idxs {
+        gv.remove(idx);
+    }
+    assert_eq!(gv.free_list_capacity(), capacity);
+}
+
+#[test]
+fn try_get() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    assert_eq!(gv.try_get(&a), Ok(&"a"));
+
+    gv.remove_no_bump(&a);
+    assert_eq!(gv.try_get(&a), Err(LookupError::NotFound));
+
+    let a = gv.push("a");
+    gv.remove(a);
+    assert_eq!(gv.try_get(&a), Err(LookupError::InvalidGeneration));
+}
+
+#[test]
+fn validate_handles() {
+    let mut other = GenerationalVector::default();
+    let _ = other.push("x");
+    let _ = other.push("y");
+    let _ = other.push("z");
+    let out_of_bounds = other.push("w");
+
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let c = gv.push("c");
+    gv.remove(b);
+
+    assert_eq!(
+        gv.validate_handles(&[a, b, c, out_of_bounds]),
+        vec![
+            Ok(()),
+            Err(LookupError::InvalidGeneration),
+            Ok(()),
+            Err(LookupError::OutOfBounds)
+        ]
+    );
+    assert!(gv.validate_handles(&[]).is_empty());
+}
+
+#[test]
+fn take_without_bump_and_reinsert() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    let b = gv.push("b");
+
+    let value = gv.take_without_bump(&a).unwrap();
+    assert_eq!(value, "a");
+    assert_eq!(gv.get(a), None);
+    assert_eq!(gv.len(), 1);
+    assert_eq!(gv.take_without_bump(&a), None);
+
+    assert_eq!(gv.reinsert(&a, value), Ok(()));
+    assert_eq!(gv.get(a), Some(&"a"));
+    assert_eq!(gv.get(b), Some(&"b"));
+    assert_eq!(gv.len(), 2);
+    assert_eq!(gv.count_num_free(), 0);
+
+    // Occupied and stale slots are rejected.
+    assert_eq!(gv.reinsert(&b, "c"), Err("c"));
+    gv.remove(b);
+    assert_eq!(gv.reinsert(&b, "b"), Err("b"));
+}
+
+#[test]
+fn growth_policy_fixed_block() {
+    let mut gv = GenerationalVector::default();
+    assert_eq!(gv.growth(), GrowthPolicy::Double);
+
+    gv.set_growth(GrowthPolicy::FixedBlock(8));
+    assert_eq!(gv.growth(), GrowthPolicy::FixedBlock(8));
+
+    let mut capacities = Vec::new();
+    for value in 0..24 {
+        gv.push(value);
+        capacities.push(gv.capacity());
+    }
+
+    assert!(capacities[..8].iter().all(|&c| c == 8));
+    assert!(capacities[8..16].iter().all(|&c| c == 16));
+    assert!(capacities[16..].iter().all(|&c| c == 24));
+}
+
+#[test]
+fn leak() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+
+    let gv: &'static mut GenerationalVector<&str> = gv.leak();
+    let b = gv.push("b");
+
+    assert_eq!(gv.get(a), Some(&"a"));
+    assert_eq!(gv.get(b), Some(&"b"));
+    assert_eq!(gv.len(), 2);
+}
+
+#[test]
+fn retain_reporting() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..10).map(|value| gv.push(value)).collect();
+    gv.remove(handles[9]);
+
+    let report = gv.retain_reporting(|&value| value % 3 == 0);
+    assert_eq!(
+        report,
+        RetainReport {
+            removed: 6,
+            remaining: 3,
+            resulting_utilization: 0.3,
+        }
+    );
+    assert_eq!(gv.get(handles[3]), Some(&3));
+    assert_eq!(gv.get(handles[4]), None);
+
+    let report = gv.retain_reporting(|_| true);
+    assert_eq!(report.removed, 0);
+    assert_eq!(report.remaining, 3);
+}
+
+#[test]
+fn get_mut_or_insert_at_existing() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+
+    *gv.get_mut_or_insert_at(0, || unreachable!()) += 10;
+    assert_eq!(gv.get(a), Some(&11));
+    assert_eq!(gv.len(), 1);
+}
+
+#[test]
+fn get_mut_or_insert_at_free_slot() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+    let b = gv.push(2);
+    gv.remove(a);
+
+    *gv.get_mut_or_insert_at(0, || 5) += 1;
+    assert_eq!(gv.get(a), None);
+    assert_eq!(gv.get(b), Some(&2));
+    assert_eq!(gv.len(), 2);
+    assert_eq!(gv.count_num_free(), 0);
+    assert_eq!(gv.iter().collect::<Vec<_>>(), vec![&6, &2]);
+}
+
+#[test]
+fn get_mut_or_insert_at_grows() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+
+    *gv.get_mut_or_insert_at(3, || 4) *= 2;
+    assert_eq!(gv.num_slots(), 4);
+    assert_eq!(gv.len(), 2);
+    assert_eq!(gv.get(a), Some(&1));
+    assert_eq!(gv.iter().collect::<Vec<_>>(), vec![&1, &8]);
+
+    // The placeholder slots are reused first.
+    let b = gv.push(2);
+    let c = gv.push(3);
+    let d = gv.push(5);
+    assert_eq!(gv.num_slots(), 5);
+    assert_eq!(gv.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &8, &5]);
+    assert_eq!(gv.get(b), Some(&2));
+    assert_eq!(gv.get(c), Some(&3));
+    assert_eq!(gv.get(d), Some(&5));
+}
+
+#[test]
+fn generation_as_u128() {
+    assert_eq!(DefaultGenerationType::one().as_u128(), 1);
+    assert_eq!(
+        DefaultGenerationType::max_generation().as_u128(),
+        usize::MAX as u128
+    );
+    assert_eq!(1u8.as_u128(), 1);
+    assert_eq!(u8::MAX.as_u128(), 255);
+    assert_eq!(42u32.as_u128(), 42);
+    assert_eq!(u64::MAX.as_u128(), u64::MAX as u128);
+    assert_eq!(u128::MAX.as_u128(), u128::MAX);
+
+    let mut gv = vector::GenerationalVector::<_, u16>::new();
+    let a = gv.push("a");
+    gv.remove(a);
+    assert_eq!(gv.generation_at(0).map(GenerationAsU128::as_u128), Some(2));
+}
+
+#[test]
+fn retain_then_shrink() {
+    let mut gv = GenerationalVector::with_capacity(64);
+    let handles: Vec<_> = (0..32).map(|value| gv.push(value)).collect();
+
+    // Keep the first four and one further entity.
+    gv.retain_then_shrink(|&value| value < 4 || value == 10);
+
+    assert_eq!(gv.len(), 5);
+    assert_eq!(gv.num_slots(), 11);
+    assert!(gv.capacity() < 64);
+    assert_eq!(gv.get(handles[0]), Some(&0));
+    assert_eq!(gv.get(handles[3]), Some(&3));
+    assert_eq!(gv.get(handles[10]), Some(&10));
+    assert_eq!(gv.get(handles[5]), None);
+    assert_eq!(gv.get(handles[20]), None);
+
+    // Interior free slots are reused.
+    let _ = gv.push(100);
+    assert_eq!(gv.num_slots(), 11);
+}
+
+#[test]
+fn iter_at_least_generation() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+    let handles: Vec<_> = (0..5).map(|value| gv.push(value)).collect();
+
+    gv.remove(handles[1]);
+    gv.remove(handles[3]);
+    let d = gv.push(30);
+    let b = gv.push(10);
+
+    let recycled: Vec<_> = gv.iter_at_least_generation(2).collect();
+    assert_eq!(recycled, vec![(b, &10), (d, &30)]);
+
+    assert_eq!(gv.iter_at_least_generation(1).count(), 5);
+    assert_eq!(gv.iter_at_least_generation(3).count(), 0);
+}
+
+#[test]
+fn clone_subset() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(String::from("a"));
+    let b = gv.push(String::from("b"));
+    let c = gv.push(String::from("c"));
+    gv.remove(b);
+
+    let (subset, mapping) = gv.clone_subset(&[c, b, a]);
+    assert_eq!(subset.len(), 2);
+    assert_eq!(mapping.len(), 3);
+    assert_eq!(subset.get(mapping[0].unwrap()), Some(&String::from("c")));
+    assert_eq!(mapping[1], None);
+    assert_eq!(subset.get(mapping[2].unwrap()), Some(&String::from("a")));
+
+    // The original is unchanged.
+    assert_eq!(gv.len(), 2);
+    assert_eq!(gv.get(a), Some(&String::from("a")));
+}
+
+#[test]
+fn debug_dump() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+    let a = gv.push("a");
+    let _b = gv.push("b");
+    let c = gv.push("c");
+    gv.remove(c);
+    gv.remove(a);
+
+    let dump = gv.debug_dump();
+    assert!(dump.contains("slot 0: generation 2, free\n"));
+    assert!(dump.contains("slot 1: generation 1, occupied: \"b\"\n"));
+    assert!(dump.contains("slot 2: generation 2, free\n"));
+    assert!(dump.ends_with(&format!("len: 1, free: 2, capacity: {}\n", gv.capacity())));
+}
+
+#[test]
+fn remove_lowest() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..4).map(|value| gv.push(value)).collect();
+    gv.remove(handles[1]);
+
+    assert_eq!(gv.remove_lowest(), Some((handles[0], 0)));
+    assert_eq!(gv.remove_lowest(), Some((handles[2], 2)));
+    assert_eq!(gv.remove_lowest(), Some((handles[3], 3)));
+    assert_eq!(gv.remove_lowest(), None);
+    assert!(gv.is_empty());
+    assert_eq!(gv.get(handles[0]), None);
+}
+
+#[test]
+fn remove_highest() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..4).map(|value| gv.push(value)).collect();
+    gv.remove(handles[2]);
+
+    assert_eq!(gv.remove_highest(), Some((handles[3], 3)));
+    assert_eq!(gv.remove_highest(), Some((handles[1], 1)));
+    assert_eq!(gv.remove_highest(), Some((handles[0], 0)));
+    assert_eq!(gv.remove_highest(), None);
+    assert!(gv.is_empty());
+}
+
+#[test]
+fn extend_from() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(String::from("a"));
+    let b = gv.push(String::from("b"));
+    gv.remove(b);
+
+    let mut other = GenerationalVector::default();
+    let x = other.push(String::from("x"));
+    let y = other.push(String::from("y"));
+    let z = other.push(String::from("z"));
+    other.remove(y);
+
+    let len = gv.len();
+    let indices = gv.extend_from(&other);
+
+    assert_eq!(gv.len(), len + other.len());
+    assert_eq!(indices.len(), 2);
+    assert_eq!(gv.get(a), Some(&String::from("a")));
+    assert_eq!(gv.get(indices[0]), Some(&String::from("x")));
+    assert_eq!(gv.get(indices[1]), Some(&String::from("z")));
+
+    // The other vector is untouched.
+    assert_eq!(other.len(), 2);
+    assert_eq!(other.get(x), Some(&String::from("x")));
+    assert_eq!(other.get(y), None);
+    assert_eq!(other.get(z), Some(&String::from("z")));
+}
+
+#[test]
+fn reserve_id_block() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(0);
+    let b = gv.push(1);
+    gv.remove(a);
+
+    let mut next = 10;
+    let (range, indices) = gv.reserve_id_block(4, || {
+        next += 1;
+        next
+    });
+
+    // The free slot is not part of the block.
+    assert_eq!(range, 2..6);
+    assert_eq!(indices.len(), 4);
+    assert_eq!(gv.len(), 5);
+    assert_eq!(gv.get(b), Some(&1));
+    for (offset, index) in indices.iter().enumerate() {
+        assert_eq!(gv.get(index), Some(&(11 + offset)));
+    }
+    assert_eq!(gv.slot_values().skip(2).count(), range.len());
+
+    let (range, indices) = gv.reserve_id_block(0, || unreachable!());
+    assert!(range.is_empty());
+    assert!(indices.is_empty());
+}
+
+#[test]
+fn find_free_run() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..10).map(|value| gv.push(value)).collect();
+
+    // Holes at 1, 3..6 and 8..10.
+    for slot in [1, 3, 4, 5, 8, 9] {
+        gv.remove(handles[slot]);
+    }
+
+    assert_eq!(gv.find_free_run(0), Some(0));
+    assert_eq!(gv.find_free_run(1), Some(1));
+    assert_eq!(gv.find_free_run(2), Some(3));
+    assert_eq!(gv.find_free_run(3), Some(3));
+    assert_eq!(gv.find_free_run(4), None);
+}
+
+#[test]
+fn find_free_run_skips_retired_slots() {
+    let mut gv = vector::GenerationalVector::new();
+    gv.set_overflow_policy(OverflowPolicy::Retire);
+    let a = exhaust_first_slot(&mut gv);
+    let b = gv.push("b");
+    gv.remove(a);
+    gv.remove(b);
+
+    assert_eq!(gv.find_free_run(1), Some(1));
+    assert_eq!(gv.find_free_run(2), None);
+}
+
+#[test]
+fn swap_values_with() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+    let b = gv.push(2);
+    let c = gv.push(3);
+    gv.remove(b);
+
+    // The buffer is longer than the number of live values.
+    let mut buffer = vec![10, 30, 50];
+    gv.swap_values_with(&mut buffer);
+
+    assert_eq!(gv.get(a), Some(&10));
+    assert_eq!(gv.get(b), None);
+    assert_eq!(gv.get(c), Some(&30));
+    assert_eq!(buffer, vec![1, 3, 50]);
+
+    // The buffer is shorter than the number of live values.
+    let mut buffer = vec![100];
+    gv.swap_values_with(&mut buffer);
+    assert_eq!(gv.get(a), Some(&100));
+    assert_eq!(gv.get(c), Some(&30));
+    assert_eq!(buffer, vec![10]);
+}
+
+#[test]
+fn slots_at_max_generation() {
+    let mut gv = vector::GenerationalVector::new();
+    let b = gv.push("b");
+    assert_eq!(gv.slots_at_max_generation(), 0);
+
+    gv.remove(b);
+    let _ = exhaust_first_slot(&mut gv);
+    let _ = gv.push("c");
+    assert_eq!(gv.slots_at_max_generation(), 1);
+}
+
+#[test]
+fn iter_collect_into() {
+    let mut gv = GenerationalVector::default();
+    let _a = gv.push(1);
+    let b = gv.push(2);
+    let _c = gv.push(3);
+    gv.remove(b);
+
+    let mut scratch = Vec::with_capacity(8);
+    gv.iter().collect_into(&mut scratch);
+    assert_eq!(scratch, vec![&1, &3]);
+
+    let capacity = scratch.capacity();
+    let pointer = scratch.as_ptr();
+
+    // The buffer is cleared before it is refilled.
+    let other: GenerationalVector<_> = vec![4, 5, 6].into();
+    other.iter().collect_into(&mut scratch);
+    assert_eq!(scratch, vec![&4, &5, &6]);
+    assert_eq!(scratch.capacity(), capacity);
+    assert_eq!(scratch.as_ptr(), pointer);
+}
+
+#[test]
+fn partition() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..10).map(|value| gv.push(value)).collect();
+    gv.remove(handles[4]);
+    gv.remove(handles[7]);
+
+    let (small, large) = gv.partition(|&value| value < 5);
+    assert!(small.iter().all(|&value| value < 5));
+    assert!(large.iter().all(|&value| value >= 5));
+
+    let mut all: Vec<_> = small.iter().chain(large.iter()).copied().collect();
+    all.sort();
+    assert_eq!(all, vec![0, 1, 2, 3, 5, 6, 8, 9]);
+
+    // Both results use fresh, dense indices.
+    assert_eq!(small.num_slots(), small.len());
+    assert_eq!(large.num_slots(), large.len());
+}
+
+#[test]
+fn is_contiguous() {
+    let mut gv = GenerationalVector::default();
+    assert!(gv.is_contiguous());
+
+    let a = gv.push(1);
+    let b = gv.push(2);
+    assert!(gv.is_contiguous());
+
+    gv.remove(a);
+    assert!(!gv.is_contiguous());
+
+    // Trailing holes are holes as well.
+    let _ = gv.push(3);
+    gv.remove(b);
+    assert!(!gv.is_contiguous());
+}
+
+#[test]
+fn handles_by_generation() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+    let mut handles: Vec<_> = (0..4).map(|value| gv.push(value)).collect();
+
+    // Recycle slot 2 twice and slots 0 and 3 once.
+    for slot in [2, 2, 0, 3] {
+        gv.remove(handles[slot]);
+        handles[slot] = gv.push(slot * 10);
+    }
+
+    assert_eq!(gv.generation_at(0), Some(2));
+    assert_eq!(gv.generation_at(1), Some(1));
+    assert_eq!(gv.generation_at(2), Some(3));
+    assert_eq!(gv.generation_at(3), Some(2));
+    assert_eq!(
+        gv.handles_by_generation(),
+        vec![handles[1], handles[0], handles[3], handles[2]]
+    );
+}
+
+#[test]
+fn generation_headroom() {
+    let mut gv = vector::GenerationalVector::<_, u8>::new();
+    let mut index = gv.push("a");
+    assert_eq!(gv.generation_headroom(&index), Some(254));
+
+    for expected in (250..254).rev() {
+        gv.remove(index);
+        assert_eq!(gv.generation_headroom(&index), Some(expected));
+        index = gv.push("a");
+        assert_eq!(gv.generation_headroom(&index), Some(expected));
+    }
+
+    let mut exhausted = vector::GenerationalVector::new();
+    let last = exhaust_first_slot(&mut exhausted);
+    assert_eq!(exhausted.generation_headroom(&last), Some(0));
+}
+
+#[test]
+fn push_batch_checked() {
+    let mut gv = GenerationalVector::with_capacity(4);
+    let a = gv.push(0);
+    let _b = gv.push(1);
+    gv.remove(a);
+
+    // One free slot and two slots of spare capacity.
+    let capacity = gv.capacity();
+    let indices = gv.push_batch_checked(vec![10, 20, 30]).unwrap();
+    assert_eq!(gv.capacity(), capacity);
+    assert_eq!(gv.num_slots(), 4);
+    assert_eq!(gv.get(indices[0]), Some(&10));
+    assert_eq!(gv.get(indices[1]), Some(&20));
+    assert_eq!(gv.get(indices[2]), Some(&30));
+
+    // Nothing is inserted if the values do not fit.
+    gv.remove(indices[1]);
+    assert_eq!(gv.push_batch_checked(vec![40, 50]), Err(vec![40, 50]));
+    assert_eq!(gv.len(), 3);
+    assert_eq!(gv.capacity(), capacity);
+}
+
+#[test]
+fn iter_mut_for_each_indexed() {
+    struct Entity {
+        id: Option<vector::GenerationalIndex<DefaultGenerationType>>,
+        name: &'static str,
+    }
+
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(Entity {
+        id: None,
+        name: "a",
+    });
+    let b = gv.push(Entity {
+        id: None,
+        name: "b",
+    });
+    let c = gv.push(Entity {
+        id: None,
+        name: "c",
+    });
+    gv.remove(b);
+
+    let mut visited = 0;
+    gv.iter_mut().for_each_indexed(|index, entity| {
+        entity.id = Some(index);
+        visited += 1;
+    });
+
+    assert_eq!(visited, 2);
+    for index in [a, c] {
+        let entity = gv.get(index).unwrap();
+        assert_eq!(entity.id, Some(index));
+    }
+    assert_eq!(gv.get(c).unwrap().name, "c");
+}
+
+#[test]
+fn dedup() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = ["a", "a", "b", "x", "b", "c", "c", "a"]
+        .into_iter()
+        .map(|value| gv.push(value))
+        .collect();
+
+    // Duplicates separated by an empty slot are adjacent in slot order.
+    gv.remove(handles[3]);
+
+    assert_eq!(gv.dedup(), 3);
+    assert_eq!(gv.iter().collect::<Vec<_>>(), vec![&"a", &"b", &"c", &"a"]);
+    assert_eq!(gv.get(handles[0]), Some(&"a"));
+    assert_eq!(gv.get(handles[1]), None);
+    assert_eq!(gv.get(handles[2]), Some(&"b"));
+    assert_eq!(gv.get(handles[4]), None);
+    assert_eq!(gv.get(handles[5]), Some(&"c"));
+    assert_eq!(gv.get(handles[6]), None);
+    assert_eq!(gv.get(handles[7]), Some(&"a"));
+
+    assert_eq!(gv.dedup(), 0);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn live_handle_set() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..5).map(|value| gv.push(value)).collect();
+    gv.remove(handles[1]);
+    gv.remove(handles[3]);
+
+    let set = gv.live_handle_set();
+    assert_eq!(set.len(), gv.len());
+    assert!(set.iter().all(|index| gv.get(index).is_some()));
+    assert!(!set.contains(&handles[1]));
+
+    // Compare against a later snapshot.
+    let added = gv.push(10);
+    gv.remove(handles[0]);
+    let later = gv.live_handle_set();
+    assert_eq!(later.difference(&set).collect::<Vec<_>>(), vec![&added]);
+    assert_eq!(
+        set.difference(&later).collect::<Vec<_>>(),
+        vec![&handles[0]]
+    );
+}
+
+#[test]
+fn split_off_slots() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..6).map(|value| gv.push(value)).collect();
+    gv.remove(handles[1]);
+    gv.remove(handles[4]);
+
+    let tail = gv.split_off_slots(3);
+
+    assert_eq!(gv.len(), 2);
+    assert_eq!(gv.get(handles[0]), Some(&0));
+    assert_eq!(gv.get(handles[2]), Some(&2));
+    for index in &handles[3..] {
+        assert_eq!(gv.get(index), None);
+    }
+
+    assert_eq!(tail.len(), 2);
+    assert_eq!(tail.iter().collect::<Vec<_>>(), vec![&3, &5]);
+
+    // Splitting beyond the end yields an empty vector.
+    assert!(gv.split_off_slots(100).is_empty());
+    assert_eq!(gv.len(), 2);
+}
+
+#[test]
+fn reinitialize_from() {
+    let mut gv = GenerationalVector::with_capacity(8);
+    let old: Vec<_> = (0..6).map(|value| gv.push(value)).collect();
+    gv.remove(old[2]);
+    let capacity = gv.capacity();
+
+    let indices = gv.reinitialize_from(vec![10, 20, 30, 40]);
+
+    assert!(old.iter().all(|index| gv.get(index).is_none()));
+    assert_eq!(gv.capacity(), capacity);
+    assert_eq!(gv.num_slots(), 6);
+    assert_eq!(gv.len(), 4);
+    for (index, value) in indices.iter().zip([10, 20, 30, 40]) {
+        assert_eq!(gv.get(index), Some(&value));
+    }
+}
+
+#[test]
+fn get_mut() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+    let b = gv.push(2);
+
+    *gv.get_mut(a).unwrap() += 10;
+    assert_eq!(gv.get(a), Some(&11));
+    assert_eq!(gv.get(b), Some(&2));
+
+    gv.remove(b);
+    assert_eq!(gv.get_mut(b), None);
+
+    // The reused slot is not reachable through the stale index.
+    let c = gv.push(3);
+    assert_eq!(gv.get_mut(b), None);
+    assert_eq!(gv.get_mut(c), Some(&mut 3));
+}
+
+#[test]
+fn index_and_index_mut() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+    let b = gv.push(2);
+
+    assert_eq!(gv[&a], 1);
+    gv[&b] += 40;
+    assert_eq!(gv[&b], 42);
+    assert_eq!(gv.get(b), Some(&42));
+}
+
+#[test]
+#[should_panic(expected = "index points to an empty or stale slot: slot 0 with generation 1")]
+fn index_panics_on_stale_index() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+    let a = gv.push(1);
+    gv.remove(a);
+    let _ = gv[&a];
+}
+
+#[test]
+#[should_panic(expected = "index points to an empty or stale slot: slot 0 with generation 1")]
+fn index_mut_panics_on_stale_index() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+    let a = gv.push(1);
+    gv.remove(a);
+    gv[&a] = 2;
+}
+
+#[test]
+fn remove_out_of_bounds_index() {
+    let mut other = GenerationalVector::default();
+    let _ = other.push("x");
+    let _ = other.push("y");
+    let foreign = other.push("z");
+
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+
+    assert_eq!(gv.remove(foreign), DeletionResult::NotFound);
+    assert_eq!(gv.remove_no_bump(&foreign), DeletionResult::NotFound);
+    assert_eq!(gv.get(a), Some(&"a"));
+    assert_eq!(gv.len(), 1);
+}
+
+#[test]
+fn take() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(String::from("a"));
+    let b = gv.push(String::from("b"));
+
+    assert_eq!(gv.take(&a), Some(String::from("a")));
+    assert_eq!(gv.len(), 1);
+    assert_eq!(gv.get(a), None);
+    assert_eq!(gv.take(&a), None);
+
+    // The slot is reused with a new generation.
+    let c = gv.push(String::from("c"));
+    assert_eq!(gv.take(&a), None);
+    assert_eq!(gv.get(c), Some(&String::from("c")));
+    assert_eq!(gv.get(b), Some(&String::from("b")));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_round_trip() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(String::from("a"));
+    let b = gv.push(String::from("b"));
+    let c = gv.push(String::from("c"));
+    gv.remove(b);
+    gv.remove(a);
+    let d = gv.push(String::from("d"));
+
+    let json = serde_json::to_string(&gv).unwrap();
+    let index_json = serde_json::to_string(&c).unwrap();
+
+    let mut restored: GenerationalVector<String> = serde_json::from_str(&json).unwrap();
+    let c_restored: vector::GenerationalIndex<DefaultGenerationType> =
+        serde_json::from_str(&index_json).unwrap();
+
+    assert_eq!(c_restored, c);
+    assert_eq!(restored.len(), 2);
+    assert_eq!(restored.get(a), None);
+    assert_eq!(restored.get(b), None);
+    assert_eq!(restored.get(c), Some(&String::from("c")));
+    assert_eq!(restored.get(d), Some(&String::from("d")));
+
+    // The free list survives, so the freed slot is reused with a new generation.
+    let e = restored.push(String::from("e"));
+    assert_eq!(restored.num_slots(), 3);
+    assert_eq!(restored.get(b), None);
+    assert_eq!(restored.get(e), Some(&String::from("e")));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_rejects_inconsistent_free_list() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+    gv.push(2);
+    gv.remove(a);
+
+    let json = serde_json::to_string(&gv).unwrap();
+    assert!(serde_json::from_str::<GenerationalVector<i32>>(&json).is_ok());
+
+    let occupied = json.replace(r#""free_list":[0]"#, r#""free_list":[1]"#);
+    let out_of_range = json.replace(r#""free_list":[0]"#, r#""free_list":[5]"#);
+    let duplicate = json.replace(r#""free_list":[0]"#, r#""free_list":[0,0]"#);
+    let unlisted = json.replace(r#""free_list":[0]"#, r#""free_list":[]"#);
+    let retired_occupied = json.replace(r#""retired":[]"#, r#""retired":[1]"#);
+    for payload in [
+        occupied,
+        out_of_range,
+        duplicate,
+        unlisted,
+        retired_occupied,
+    ] {
+        assert_ne!(payload, json);
+        assert!(serde_json::from_str::<GenerationalVector<i32>>(&payload).is_err());
+    }
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde_rejects_zero_default_generation() {
+    assert!(serde_json::from_str::<DefaultGenerationType>("1").is_ok());
+    assert!(serde_json::from_str::<DefaultGenerationType>("0").is_err());
+}
+
+#[test]
+fn clear() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..10).map(|value| gv.push(value)).collect();
+    gv.remove(handles[3]);
+    let capacity = gv.capacity();
+
+    gv.clear();
+
+    assert!(gv.is_empty());
+    assert_eq!(gv.capacity(), capacity);
+    assert_eq!(gv.num_slots(), 10);
+    assert_eq!(gv.count_num_free(), 10);
+    assert!(handles.iter().all(|index| gv.get(index).is_none()));
+
+    // Slots are reused without growing the vector.
+    let a = gv.push(42);
+    assert_eq!(gv.num_slots(), 10);
+    assert_eq!(gv.get(a), Some(&42));
+    assert!(handles.iter().all(|index| gv.get(index).is_none()));
+}
+
+#[test]
+fn retain() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+    let handles: Vec<_> = (0..6).map(|value| gv.push(value)).collect();
+
+    gv.retain(|&value| value % 2 == 0);
+
+    assert_eq!(gv.len(), 3);
+    for (value, index) in handles.iter().enumerate() {
+        if value % 2 == 0 {
+            assert_eq!(gv.get(index), Some(&value));
+            assert_eq!(gv.generation_delta(index), Some(0));
+        } else {
+            assert_eq!(gv.get(index), None);
+            assert_eq!(gv.generation_delta(index), Some(1));
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn par_iter() {
+    use rayon::prelude::*;
+
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..100_000u64).map(|value| gv.push(value)).collect();
+    for index in handles.iter().step_by(3) {
+        gv.remove(index);
+    }
+
+    let sequential: u64 = gv.iter().sum();
+    assert_eq!(gv.par_iter().sum::<u64>(), sequential);
+    assert_eq!(gv.par_iter().count(), gv.len());
+
+    gv.par_iter_mut().for_each(|value| *value *= 2);
+    assert_eq!(gv.iter().sum::<u64>(), sequential * 2);
+    assert_eq!(gv.get(handles[1]), Some(&2));
+}
+
+#[test]
+fn iter_with_indices() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let _c = gv.push("c");
+    gv.remove(b);
+
+    let pairs: Vec<_> = gv.iter_with_indices().collect();
+    assert_eq!(pairs.len(), 2);
+    assert_eq!(pairs[0], (a, &"a"));
+    for (index, value) in pairs {
+        assert_eq!(gv.get(index), Some(value));
+    }
+}
+
+#[test]
+fn iter_mut_with_indices() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+    let b = gv.push(2);
+    let c = gv.push(3);
+    gv.remove(b);
+
+    let mut seen = Vec::new();
+    for (index, value) in gv.iter_mut_with_indices() {
+        *value *= 10;
+        seen.push(index);
+    }
+
+    assert_eq!(seen, vec![a, c]);
+    assert_eq!(gv.get(a), Some(&10));
+    assert_eq!(gv.get(c), Some(&30));
+}
+
+#[test]
+fn keys() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..6).map(|value| gv.push(value)).collect();
+    gv.remove(handles[0]);
+    gv.remove(handles[4]);
+
+    let keys: Vec<_> = gv.keys().collect();
+    assert_eq!(keys.len(), gv.len());
+    assert_eq!(keys, vec![handles[1], handles[2], handles[3], handles[5]]);
+
+    // The order matches `iter`.
+    let values: Vec<_> = keys.iter().map(|index| gv.get(index).unwrap()).collect();
+    assert_eq!(values, gv.iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn clone() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(String::from("a"));
+    let b = gv.push(String::from("b"));
+    let c = gv.push(String::from("c"));
+    gv.remove(b);
+
+    let mut copy = gv.clone();
+    for index in [a, b, c] {
+        assert_eq!(copy.get(index), gv.get(index));
+    }
+    assert_eq!(copy.len(), gv.len());
+    assert_eq!(copy.num_slots(), gv.num_slots());
+
+    // Both evolve independently.
+    copy[&a].push('!');
+    let d = copy.push(String::from("d"));
+    assert_eq!(gv.get(a), Some(&String::from("a")));
+    assert_eq!(copy.get(a), Some(&String::from("a!")));
+    assert_eq!(gv.get(d), None);
+    assert_eq!(gv.count_num_free(), 1);
+}
+
+#[test]
+fn get_disjoint_mut() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+    let b = gv.push(2);
+
+    let (x, y) = gv.get_disjoint_mut(&b, &a).unwrap();
+    std::mem::swap(x, y);
+    assert_eq!(gv.get(a), Some(&2));
+    assert_eq!(gv.get(b), Some(&1));
+}
+
+#[test]
+fn get_disjoint_mut_equal_indices() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+    assert!(gv.get_disjoint_mut(&a, &a).is_none());
+}
+
+#[test]
+fn get_disjoint_mut_stale_index() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+    let b = gv.push(2);
+    gv.remove(b);
+    let _c = gv.push(3);
+
+    // The slot of `b` is occupied again, but by a different generation.
+    assert!(gv.get_disjoint_mut(&a, &b).is_none());
+    assert!(gv.get_disjoint_mut(&b, &a).is_none());
+}
+
+#[test]
+fn swap() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    let b = gv.push("b");
+    let c = gv.push("c");
+
+    assert!(gv.swap(&a, &c));
+    assert_eq!(gv.get(a), Some(&"c"));
+    assert_eq!(gv.get(b), Some(&"b"));
+    assert_eq!(gv.get(c), Some(&"a"));
+
+    // Swapping an element with itself is a no-op.
+    assert!(gv.swap(&b, &b));
+    assert_eq!(gv.get(b), Some(&"b"));
+
+    gv.remove(b);
+    assert!(!gv.swap(&a, &b));
+    assert!(!gv.swap(&b, &b));
+    assert_eq!(gv.get(a), Some(&"c"));
+}
+
+#[test]
+fn shrink_to_fit() {
+    let mut gv = GenerationalVector::with_capacity(32);
+    let handles: Vec<_> = (0..8).map(|value| gv.push(value)).collect();
+
+    // Free an interior slot and the trailing ones.
+    gv.remove(handles[2]);
+    for handle in &handles[5..] {
+        gv.remove(handle);
+    }
+
+    gv.shrink_to_fit();
+
+    assert_eq!(gv.num_slots(), 5);
+    assert!(gv.capacity() < 32);
+    assert_eq!(gv.get(handles[0]), Some(&0));
+    assert_eq!(gv.get(handles[4]), Some(&4));
+    assert_eq!(gv.get(handles[2]), None);
+
+    // Only the interior slot remains on the free list.
+    gv.push(100);
+    assert_eq!(gv.num_slots(), 5);
+    gv.push(101);
+    assert_eq!(gv.num_slots(), 6);
+}
+
+#[test]
+fn shrink_to_fit_keeps_trimmed_indices_stale() {
+    let mut gv = GenerationalVector::default();
+    let _a = gv.push("a");
+    let b = gv.push("b");
+    gv.remove(b);
+    gv.shrink_to_fit();
+
+    let c = gv.push("c");
+    assert_eq!(c.index(), b.index());
+    assert_ne!(c, b);
+    assert_eq!(gv.get(b), None);
+    assert_eq!(gv.get(c), Some(&"c"));
+
+    // The same holds for slots recreated empty.
+    gv.remove(c);
+    gv.retain_then_shrink(|_| true);
+    assert_eq!(gv.grow_with_empty(1), 1..2);
+    assert_eq!(gv.get_mut_or_insert_at(1, || "d"), &"d");
+    let (d, _) = gv.last().unwrap();
+    assert_eq!(d.index(), c.index());
+    assert_eq!(gv.get(b), None);
+    assert_eq!(gv.get(c), None);
+}
+
+#[test]
+fn prune_trailing_free_reporting_keeps_retired_slots() {
+    let mut gv = vector::GenerationalVector::new();
+    gv.set_overflow_policy(OverflowPolicy::Retire);
+    let index = exhaust_first_slot(&mut gv);
+    gv.remove(index);
+
+    // The retired slot cannot be handed out at a higher generation.
+    assert!(gv.prune_trailing_free_reporting().is_empty());
+    assert_eq!(gv.num_slots(), 1);
+    let other = gv.push("b");
+    assert_eq!(other.index(), 1);
+    assert_eq!(gv.get(index), None);
+}
+
+#[test]
+fn get_or_insert_with() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+
+    // Hit: the existing value is returned and the factory is not called.
+    assert_eq!(*gv.get_or_insert_with(&a, || unreachable!()), "a");
+    *gv.get_or_insert_with(&a, || unreachable!()) = "A";
+    assert_eq!(gv.get(a), Some(&"A"));
+
+    // Fill: the empty slot is reused with its generation.
+    gv.take_without_bump(&a);
+    assert_eq!(*gv.get_or_insert_with(&a, || "b"), "b");
+    assert_eq!(gv.get(a), Some(&"b"));
+    assert_eq!(gv.len(), 1);
+    assert_eq!(gv.count_num_free(), 0);
+}
+
+#[test]
+#[should_panic(expected = "different generation")]
+fn get_or_insert_with_panics_on_stale_index() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    gv.remove(a);
+
+    gv.get_or_insert_with(&a, || "b");
+}
+
+#[test]
+#[should_panic(expected = "different generation")]
+fn get_or_insert_with_panics_on_occupied_slot() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    gv.remove(a);
+    gv.push("b");
+
+    gv.get_or_insert_with(&a, || "c");
+}
+
+#[test]
+fn replace() {
+    let mut gv = GenerationalVector::default();
+    let a = gv.push("a");
+    let b = gv.push("b");
+
+    // Live slot: the value is swapped and the index stays valid.
+    assert_eq!(gv.replace(&a, "A"), Some("a"));
+    assert_eq!(gv.get(a), Some(&"A"));
+    assert_eq!(gv.get(b), Some(&"b"));
+
+    // Stale slot: nothing changes, including the slot that reused it.
+    gv.remove(a);
+    let c = gv.push("c");
+    assert_eq!(gv.replace(&a, "X"), None);
+    assert_eq!(gv.get(c), Some(&"c"));
+    assert_eq!(gv.len(), 2);
+
+    // Empty slot.
+    gv.remove(b);
+    assert_eq!(gv.replace(&b, "X"), None);
+    assert_eq!(gv.len(), 1);
+}
+
+#[test]
+fn from_iterator() {
+    let collected: GenerationalVector<_> = (0..10).collect();
+    assert_eq!(collected.len(), 10);
+    assert_eq!(collected.count_num_free(), 0);
+
+    // Every index handed out by pushing the same values resolves identically.
+    let mut pushed = GenerationalVector::default();
+    for value in 0..10 {
+        let index = pushed.push(value);
+        assert_eq!(collected.get(index), Some(&value));
+    }
+}
+
+#[test]
+fn extend() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..4).map(|value| gv.push(value)).collect();
+    gv.remove(handles[1]);
+    gv.remove(handles[2]);
+
+    // The two freed slots are reused before the tail grows.
+    gv.extend([10, 11]);
+    assert_eq!(gv.len(), 4);
+    assert_eq!(gv.num_slots(), 4);
+    assert_eq!(gv.count_num_free(), 0);
+
+    gv.extend(&[12, 13]);
+    assert_eq!(gv.len(), 6);
+    assert_eq!(gv.num_slots(), 6);
+
+    let mut values: Vec<_> = gv.iter().copied().collect();
+    values.sort();
+    assert_eq!(values, vec![0, 3, 10, 11, 12, 13]);
+}
+
+#[test]
+fn with_max_capacity() {
+    let mut gv = GenerationalVector::with_max_capacity(3);
+
+    // Up to the ceiling.
+    let handles: Vec<_> = (0..3).map(|value| gv.try_push(value).unwrap()).collect();
+    assert_eq!(gv.len(), 3);
+
+    // Past the ceiling the value is handed back.
+    assert_eq!(gv.try_push(3), Err(3));
+    assert_eq!(gv.len(), 3);
+
+    // Removing an element makes room again.
+    gv.remove(handles[0]);
+    assert!(gv.try_push(4).is_ok());
+    assert_eq!(gv.try_push(5), Err(5));
+
+    // The ordinary push stays unbounded.
+    gv.push(6);
+    assert_eq!(gv.len(), 4);
+    assert_eq!(gv.try_push(7), Err(7));
+}
+
+#[test]
+fn default_generation_type_saturating_add() {
+    let max = DefaultGenerationType::max_generation();
+    let one = DefaultGenerationType::one();
+
+    assert_eq!(one + one, one.saturating_add(one));
+    assert_eq!(max.saturating_add(one), max);
+}
+
+#[test]
+#[should_panic(expected = "generation counter overflowed")]
+fn default_generation_type_add_overflow() {
+    let _ = DefaultGenerationType::max_generation() + DefaultGenerationType::one();
+}
+
+#[test]
+fn wrapping_generation_type() {
+    let one = WrappingGenerationType::one();
+    let max = WrappingGenerationType::max_generation();
+    assert_eq!((one + one).get(), 2);
+
+    // Wrapping past the maximum skips zero.
+    assert_eq!(max + one, one);
+    assert_eq!((max + (one + one)).get(), 2);
+
+    // Multiplication wraps the same way and never panics.
+    let two = one + one;
+    assert_eq!(max * one, max);
+    assert_eq!(two * one, two);
+    assert_eq!((two * two).get(), 4);
+    assert_eq!((max * two) + one, one);
+    let half = (0..31).fold(one, |value, _| value * two);
+    assert_eq!(half.get(), 1 << 31);
+    assert_eq!(half * two, one);
+
+    // A vector never panics when a slot wraps around.
+    let layout = vec![(max, true)];
+    let mut gv = vector::GenerationalVector::<_, WrappingGenerationType>::from_layout_and_values(
+        layout,
+        vec!["a"],
+    )
+    .unwrap();
+    let a = gv.iter_with_indices().next().unwrap().0;
+
+    assert_eq!(gv.remove(a), DeletionResult::Ok);
+    assert_eq!(gv.generation_at(0), Some(one));
+    let b = gv.push("b");
+    assert_eq!(gv.get(a), None);
+    assert_eq!(gv.get(b), Some(&"b"));
+}
+
+#[test]
+fn index_parts_round_trip() {
+    let mut gv = vector::GenerationalVector::<_, u32>::new();
+    let a = gv.push("a");
+    gv.remove(a);
+    let b = gv.push("b");
+
+    assert_eq!(b.index(), a.index());
+    assert_eq!(b.generation(), a.generation() + 1);
+
+    // Rebuild from a custom wire format.
+    let wire = format!("{}:{}", b.index(), b.generation());
+    let (index, generation) = wire.split_once(':').unwrap();
+    let restored =
+        vector::GenerationalIndex::from_parts(index.parse().unwrap(), generation.parse().unwrap());
+
+    assert_eq!(restored, b);
+    assert_eq!(gv.get(restored), Some(&"b"));
+}
+
+#[test]
+fn index_as_hash_map_key() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..4).map(|value| gv.push(value)).collect();
+
+    let names: HashMap<_, String> = handles
+        .iter()
+        .map(|handle| (*handle, format!("entity {}", gv[handle])))
+        .collect();
+    assert_eq!(names.len(), 4);
+    assert_eq!(names[&handles[2]], "entity 2");
+
+    // A reused slot produces a distinct key.
+    gv.remove(handles[1]);
+    let reused = gv.push(10);
+    assert!(!names.contains_key(&reused));
+    assert_eq!(names.get(&handles[1]).map(String::as_str), Some("entity 1"));
+}
+
+#[test]
+fn compact() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..8).map(|value| gv.push(value)).collect();
+    for handle in handles.iter().step_by(3) {
+        gv.remove(handle);
+    }
+
+    // Bump the generation of a surviving slot to ensure it is carried along.
+    gv.remove(handles[4]);
+    let bumped = gv.push(40);
+    assert_eq!(bumped.index(), 4);
+
+    let expected: Vec<_> = gv
+        .iter_with_indices()
+        .map(|(index, value)| (index, *value))
+        .collect();
+    let remap = gv.compact();
+
+    assert_eq!(gv.len(), expected.len());
+    assert_eq!(gv.num_slots(), expected.len());
+    assert_eq!(gv.count_num_free(), 0);
+    assert_eq!(remap.len(), expected.len());
+
+    for ((old, new), (index, value)) in remap.iter().zip(&expected) {
+        assert_eq!(old, index);
+        assert_eq!(gv.get(new), Some(value));
+        if old.index() == new.index() {
+            assert_eq!(old, new);
+        } else {
+            assert!(new.generation() > old.generation());
+            assert_eq!(gv.get(old), None);
+        }
+    }
+
+    // No index issued before compacting resolves to a different element.
+    let new_indices: Vec<_> = remap.iter().map(|(_, new)| *new).collect();
+    for handle in handles.iter().chain([&bumped]) {
+        if !new_indices.contains(handle) {
+            assert_eq!(gv.get(handle), None);
+        }
+    }
+
+    // Pushing after compacting appends to the dense tail.
+    let e = gv.push(100);
+    assert_eq!(e.index(), expected.len());
+    assert!(handles.iter().all(|handle| *handle != e));
+}
+
+#[test]
+fn compact_keeps_removed_indices_stale() {
+    let mut gv = GenerationalVector::default();
+    let x = gv.push("x");
+    let y = gv.push("y");
+    gv.remove(x);
+
+    let remap = gv.compact();
+    assert_eq!(remap.len(), 1);
+    let (old, new) = remap[0];
+    assert_eq!(old, y);
+    assert_eq!(new.index(), x.index());
+    assert!(new.generation() > x.generation());
+    assert!(new.generation() > y.generation());
+
+    assert_eq!(gv.get(x), None);
+    assert_eq!(gv.get(y), None);
+    assert_eq!(gv.get(new), Some(&"y"));
+
+    // The trimmed slot of the moved element does not revive its old index.
+    let z = gv.push("z");
+    assert_eq!(z.index(), y.index());
+    assert_eq!(gv.get(y), None);
+}
+
+#[test]
+fn compact_skips_retired_slots() {
+    let mut gv = vector::GenerationalVector::new();
+    gv.set_overflow_policy(OverflowPolicy::Retire);
+    let retired = exhaust_first_slot(&mut gv);
+    let b = gv.push("b");
+    gv.remove(retired);
+
+    let remap = gv.compact();
+    assert_eq!(remap, vec![(b, b)]);
+    assert_eq!(gv.get(retired), None);
+    assert_eq!(gv.get(b), Some(&"b"));
+    assert_eq!(gv.num_slots(), 2);
+}
+
+#[test]
+fn iter_rev() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..8).map(|value| gv.push(value)).collect();
+    for handle in handles.iter().step_by(3) {
+        gv.remove(handle);
+    }
+
+    let mut forward: Vec<_> = gv.iter().copied().collect();
+    forward.reverse();
+    let backward: Vec<_> = gv.iter().rev().copied().collect();
+    assert_eq!(backward, forward);
+
+    let mut forward: Vec<_> = gv.iter_with_indices().collect();
+    forward.reverse();
+    let backward: Vec<_> = gv.iter_with_indices().rev().collect();
+    assert_eq!(backward, forward);
+}
+
+#[test]
+fn iter_mut_rev() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..8).map(|value| gv.push(value)).collect();
+    for handle in handles.iter().step_by(3) {
+        gv.remove(handle);
+    }
+
+    let mut forward: Vec<_> = gv.iter().copied().collect();
+    forward.reverse();
+    let backward: Vec<_> = gv.iter_mut().rev().map(|value| *value).collect();
+    assert_eq!(backward, forward);
+
+    for (offset, value) in gv.iter_mut().rev().enumerate() {
+        *value = offset;
+    }
+    assert_eq!(gv.iter().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn iter_from_both_ends() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..6).map(|value| gv.push(value)).collect();
+    gv.remove(handles[2]);
+    gv.remove(handles[5]);
+
+    let mut iter = gv.iter();
+    assert_eq!(iter.next(), Some(&0));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.next_back(), Some(&3));
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let mut iter = gv.iter_mut();
+    assert_eq!(iter.next_back().copied(), Some(4));
+    assert_eq!(iter.next().copied(), Some(0));
+    assert_eq!(iter.next().copied(), Some(1));
+    assert_eq!(iter.next_back().copied(), Some(3));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn iter_exact_size() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..8).map(|value| gv.push(value)).collect();
+    for handle in handles.iter().step_by(3) {
+        gv.remove(handle);
+    }
+
+    let mut iter = gv.iter();
+    assert_eq!(iter.len(), gv.len());
+    for remaining in (0..gv.len()).rev() {
+        iter.next();
+        assert_eq!(iter.len(), remaining);
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+    }
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.len(), 0);
+
+    let mut iter = gv.iter();
+    iter.next_back();
+    iter.next();
+    assert_eq!(iter.len(), gv.len() - 2);
+}
+
+#[test]
+fn iter_mut_exact_size() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..8).map(|value| gv.push(value)).collect();
+    gv.remove(handles[0]);
+    gv.remove(handles[7]);
+    let len = gv.len();
+
+    let mut iter = gv.iter_mut();
+    assert_eq!(iter.len(), len);
+    iter.next();
+    iter.next_back();
+    assert_eq!(iter.len(), len - 2);
+    assert_eq!(iter.count(), len - 2);
+}
+
+#[test]
+fn into_iter_exact_size() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..8).map(|value| gv.push(value)).collect();
+    gv.remove(handles[3]);
+    let len = gv.len();
+
+    let mut iter = gv.into_iter();
+    assert_eq!(iter.len(), len);
+    iter.next();
+    assert_eq!(iter.len(), len - 1);
+    assert_eq!(iter.collect::<Vec<_>>().len(), len - 1);
+}
+
+#[test]
+fn size_hint_with_holes() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..10).map(|value| gv.push(value)).collect();
+    for handle in handles.iter().step_by(2) {
+        gv.remove(handle);
+    }
+    assert_eq!(gv.num_slots(), 10);
+
+    assert_eq!(gv.iter().size_hint(), (5, Some(5)));
+    assert_eq!(gv.iter_with_indices().size_hint(), (5, Some(5)));
+    assert_eq!(gv.keys().size_hint(), (5, Some(5)));
+    assert_eq!(gv.iter_mut().size_hint(), (5, Some(5)));
+    assert_eq!(gv.iter_mut_with_indices().size_hint(), (5, Some(5)));
+    assert_eq!(gv.clone().into_iter().size_hint(), (5, Some(5)));
+    assert_eq!(
+        gv.clone().into_iter().with_handles().size_hint(),
+        (5, Some(5))
+    );
+
+    // Filtering may drop any of the remaining elements.
+    let mut filtered = gv.iter().filter_indices(|&value| value > 5);
+    assert_eq!(filtered.size_hint(), (0, Some(5)));
+    assert_eq!(filtered.next().map(|(_, value)| *value), Some(7));
+    assert_eq!(filtered.size_hint(), (0, Some(1)));
+}
+
+#[test]
+fn iterators_are_fused() {
+    fn assert_fused<I: std::iter::FusedIterator>(mut iter: I) {
+        while iter.next().is_some() {}
+        for _ in 0..3 {
+            assert!(iter.next().is_none());
+        }
+    }
+
+    let mut gv = GenerationalVector::default();
+    let a = gv.push(1);
+    let _b = gv.push(2);
+    gv.remove(a);
+
+    assert_fused(gv.iter());
+    assert_fused(gv.iter_with_indices());
+    assert_fused(gv.keys());
+    assert_fused(gv.iter().filter_indices(|_| true));
+    assert_fused(gv.iter_mut());
+    assert_fused(gv.iter_mut_with_indices());
+    assert_fused(gv.clone().into_iter().with_handles());
+    assert_fused(gv.into_iter());
+}
+
+#[test]
+fn get_unchecked() {
+    let mut gv = GenerationalVector::default();
+    let handles: Vec<_> = (0..8).map(|value| gv.push(value)).collect();
+    for handle in handles.iter().step_by(2) {
+        gv.remove(handle);
+    }
+
+    let live: Vec<_> = gv.keys().collect();
+    for index in &live {
+        assert!(gv.contains_key(index));
+        unsafe {
+            assert_eq!(gv.get_unchecked(index), gv.get(index).unwrap());
+            *gv.get_unchecked_mut(index) *= 10;
+        }
+    }
+
+    assert_eq!(gv.iter().copied().collect::<Vec<_>>(), vec![10, 30, 50, 70]);
+}
+
+#[test]
+fn first_and_last() {
+    let mut gv = GenerationalVector::default();
+    assert_eq!(gv.first(), None);
+    assert_eq!(gv.last(), None);
+
+    let handles: Vec<_> = (0..6).map(|value| gv.push(value)).collect();
+    gv.remove(handles[0]);
+    gv.remove(handles[1]);
+    gv.remove(handles[5]);
+
+    let (first, value) = gv.first().unwrap();
+    assert_eq!(first, handles[2]);
+    assert_eq!(gv.get(first), Some(value));
+
+    let (last, value) = gv.last().unwrap();
+    assert_eq!(last, handles[4]);
+    assert_eq!(gv.get(last), Some(value));
+
+    gv.clear();
+    assert_eq!(gv.first(), None);
+    assert_eq!(gv.last(), None);
+}